@@ -0,0 +1,61 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use meander::generic_array::functional::FunctionalSequence;
+use meander::generic_array::GenericArray;
+use meander::typenum::U12;
+use meander::{Meander, Meander1D, Meander3};
+
+fn evaluate(c: &mut Criterion) {
+    let m: Meander<U12> = meander::rand::random();
+    let mut group = c.benchmark_group("evaluate");
+    group.bench_function("clone_and_map", |b| {
+        b.iter(|| m.curves.clone().map(|c| c.evaluate(black_box(0.5))))
+    });
+    group.bench_function("evaluate", |b| {
+        b.iter(|| m.evaluate(black_box(0.5)))
+    });
+    group.bench_function("evaluate_into", |b| {
+        let mut out = GenericArray::default();
+        b.iter(|| {
+            m.evaluate_into(black_box(0.5), &mut out);
+            black_box(&out);
+        })
+    });
+    group.finish();
+}
+
+fn evaluate_array(c: &mut Criterion) {
+    let m: Meander3 = meander::rand::random();
+    let mut group = c.benchmark_group("evaluate_array");
+    group.bench_function("evaluate", |b| {
+        b.iter(|| m.evaluate(black_box(0.5)))
+    });
+    group.bench_function("evaluate_array", |b| {
+        b.iter(|| m.evaluate_array(black_box(0.5)))
+    });
+    group.finish();
+}
+
+fn evaluate_block(c: &mut Criterion) {
+    let curve: Meander1D = meander::rand::random();
+    let mut out = vec![0.0; 4096];
+    let mut group = c.benchmark_group("evaluate_block");
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for (i, o) in out.iter_mut().enumerate() {
+                *o = curve.evaluate(black_box(0.5) + i as f64 * 0.01);
+            }
+            black_box(&out);
+        })
+    });
+    group.bench_function("block", |b| {
+        b.iter(|| {
+            curve.evaluate_block(black_box(0.5), 0.01, &mut out);
+            black_box(&out);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, evaluate, evaluate_array, evaluate_block);
+criterion_main!(benches);
@@ -0,0 +1,33 @@
+//! Checks that the core API is usable from a `no_std` crate.
+//!
+//! To make sure the crate itself builds without `std`, run:
+//!
+//! ```text
+//! cargo build --example no_std --no-default-features --features libm
+//! ```
+
+#![no_std]
+
+use meander::generic_array::GenericArray;
+use meander::typenum::U3;
+use meander::{Meander, Meander1D, UnitSinusoid};
+
+/// Compute the color of an LED at time `t`.
+pub fn led_color(meander: &Meander<U3>, t: f64) -> GenericArray<u8, U3> {
+    let values = meander.evaluate(t);
+    GenericArray::clone_from_slice(&[
+        (values[0] * 255.0) as u8,
+        (values[1] * 255.0) as u8,
+        (values[2] * 255.0) as u8,
+    ])
+}
+
+/// Evaluate a single sinusoid without going through `Meander`.
+pub fn brightness(sinusoid: UnitSinusoid, t: f64) -> f64 {
+    sinusoid.evaluate(t)
+}
+
+/// Evaluate a single curve.
+pub fn level(curve: &Meander1D, t: f64) -> f64 {
+    curve.evaluate(t)
+}
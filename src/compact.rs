@@ -0,0 +1,244 @@
+//! A compact serialization format for meanders, made of nested arrays of numbers
+//! instead of named fields.
+//!
+//! Each `UnitSinusoid` is written as `[frequency, phase]`, each `Meander1D` as an
+//! array of its sinusoids, and each `Meander` as an array of its curves. A
+//! sinusoid whose amplitude, offset or weight differs from the default is written
+//! as `[frequency, phase, amplitude, offset, weight]` instead, so nothing is lost.
+//! If a curve's envelope isn't the default, it is written as one more sinusoid
+//! after the components.
+//!
+//! This is opted into with `#[serde(with = "meander::compact")]` on a field:
+//!
+//! ```
+//! use meander::typenum::U3;
+//! use meander::Meander;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "meander::compact")]
+//!     colors: Meander<U3>,
+//! }
+//!
+//! let config = Config { colors: meander::rand::random() };
+//! let json = serde_json::to_string(&config).unwrap();
+//!
+//! let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+//! let curves = value["colors"].as_array().unwrap();
+//! assert_eq!(curves.len(), 3);
+//! for curve in curves {
+//!     for s in curve.as_array().unwrap() {
+//!         let s = s.as_array().unwrap();
+//!         assert_eq!(s.len(), 2);
+//!         assert!(s.iter().all(|x| x.is_number()));
+//!     }
+//! }
+//!
+//! let back: Config = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back.colors, config.colors);
+//!
+//! let config = Config {
+//!     colors: Meander::random_with_envelope((0.01, 0.1), &mut meander::rand::thread_rng()),
+//! };
+//! let json = serde_json::to_string(&config).unwrap();
+//! let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+//! assert_eq!(value["colors"][0].as_array().unwrap().len(), 4);
+//! let back: Config = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back.colors, config.colors);
+//! ```
+//!
+//! This requires the `serde` feature.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use generic_array::{ArrayLength, GenericArray};
+use serde::de::{self, Deserializer, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{default_envelope, is_default_envelope, Haversin, Meander, Meander1D, UnitSinusoid};
+
+/// Serialize `meander` in the compact format.
+pub fn serialize<S, D, N, W>(meander: &Meander<D, N, W>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    let mut seq = serializer.serialize_seq(Some(D::USIZE))?;
+    for c in meander.curves.iter() {
+        seq.serialize_element(&CompactCurve(c))?;
+    }
+    seq.end()
+}
+
+/// Deserialize a meander from the compact format.
+pub fn deserialize<'de, De, D, N, W>(deserializer: De) -> Result<Meander<D, N, W>, De::Error>
+where
+    De: Deserializer<'de>,
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    let curves = deserializer.deserialize_seq(ArrayVisitor::<OwnedCurve<N, W>, _, _>(PhantomData))?;
+    Ok(Meander { curves })
+}
+
+struct CompactSinusoid<'a, W>(&'a UnitSinusoid<W>);
+
+impl<'a, W> Serialize for CompactSinusoid<'a, W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let s = self.0;
+        if s.amplitude == 1.0 && s.offset == 0.0 && s.weight == 1.0 {
+            [s.frequency, s.phase].serialize(serializer)
+        } else {
+            [s.frequency, s.phase, s.amplitude, s.offset, s.weight].serialize(serializer)
+        }
+    }
+}
+
+struct CompactCurve<'a, N: ArrayLength<UnitSinusoid<W>>, W>(&'a Meander1D<N, W>);
+
+impl<'a, N: ArrayLength<UnitSinusoid<W>>, W> Serialize for CompactCurve<'a, N, W> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let c = self.0;
+        let envelope = !is_default_envelope(&c.envelope);
+        let mut seq = serializer.serialize_seq(Some(N::USIZE + envelope as usize))?;
+        for s in c.components.iter() {
+            seq.serialize_element(&CompactSinusoid(s))?;
+        }
+        if envelope {
+            seq.serialize_element(&CompactSinusoid(&c.envelope))?;
+        }
+        seq.end()
+    }
+}
+
+struct OwnedSinusoid<W>(UnitSinusoid<W>);
+
+impl<W> From<OwnedSinusoid<W>> for UnitSinusoid<W> {
+    fn from(s: OwnedSinusoid<W>) -> Self {
+        s.0
+    }
+}
+
+struct SinusoidVisitor<W>(PhantomData<W>);
+
+impl<'de, W: Default> Visitor<'de> for SinusoidVisitor<W> {
+    type Value = OwnedSinusoid<W>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array of 2 or 5 numbers")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut params = [0.0; 5];
+        let mut len = 0;
+        while let Some(x) = seq.next_element::<f64>()? {
+            if len == params.len() {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+            params[len] = x;
+            len += 1;
+        }
+        let [frequency, phase, amplitude, offset, weight] = match len {
+            2 => [params[0], params[1], 1.0, 0.0, 1.0],
+            5 => params,
+            _ => return Err(de::Error::invalid_length(len, &self)),
+        };
+        Ok(OwnedSinusoid(UnitSinusoid {
+            frequency,
+            phase,
+            amplitude,
+            offset,
+            weight,
+            waveform: W::default(),
+        }))
+    }
+}
+
+impl<'de, W: Default> Deserialize<'de> for OwnedSinusoid<W> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_seq(SinusoidVisitor(PhantomData))
+    }
+}
+
+struct OwnedCurve<N: ArrayLength<UnitSinusoid<W>>, W>(Meander1D<N, W>);
+
+impl<N: ArrayLength<UnitSinusoid<W>>, W> From<OwnedCurve<N, W>> for Meander1D<N, W> {
+    fn from(c: OwnedCurve<N, W>) -> Self {
+        c.0
+    }
+}
+
+impl<'de, N, W> Deserialize<'de> for OwnedCurve<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_seq(CurveVisitor(PhantomData))
+    }
+}
+
+/// Reads the `N` components of a curve, followed by its envelope if there is one.
+struct CurveVisitor<N, W>(PhantomData<(N, W)>);
+
+impl<'de, N, W> Visitor<'de> for CurveVisitor<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    type Value = OwnedCurve<N, W>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of length {} or {}", N::USIZE, N::USIZE + 1)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut components: GenericArray<UnitSinusoid<W>, N> = GenericArray::default();
+        for (i, slot) in components.iter_mut().enumerate() {
+            *slot = seq.next_element::<OwnedSinusoid<W>>()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?
+                .into();
+        }
+        let envelope = seq.next_element::<OwnedSinusoid<Haversin>>()?
+            .map_or_else(default_envelope, UnitSinusoid::from);
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(N::USIZE + 2, &self));
+        }
+        Ok(OwnedCurve(Meander1D { components, envelope }))
+    }
+}
+
+/// Reads an array of exactly `L::USIZE` elements, each in the format of `E`, into a
+/// `GenericArray<T, L>`.
+struct ArrayVisitor<E, T, L>(PhantomData<(E, T, L)>);
+
+impl<'de, E, T, L> Visitor<'de> for ArrayVisitor<E, T, L>
+where
+    E: Deserialize<'de> + Into<T>,
+    T: Default,
+    L: ArrayLength<T>,
+{
+    type Value = GenericArray<T, L>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of length {}", L::USIZE)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut out: GenericArray<T, L> = GenericArray::default();
+        for (i, slot) in out.iter_mut().enumerate() {
+            *slot = seq.next_element::<E>()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?
+                .into();
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(L::USIZE + 1, &self));
+        }
+        Ok(out)
+    }
+}
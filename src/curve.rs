@@ -0,0 +1,67 @@
+use generic_array::ArrayLength;
+
+use crate::{Meander1D, UnitSinusoid, Waveform};
+#[cfg(feature = "alloc")]
+use crate::CompositeCurve;
+
+/// A function of time that determines the motion of a single variable.
+///
+/// This is implemented by `UnitSinusoid`, `Meander1D` and `CompositeCurve`, as well
+/// as by `f64` (as a curve that stays at that value), and can be implemented by
+/// other types to mix custom motion into a `BoxedMeander`. The trait is object
+/// safe, so curves of different types can be stored together as `Box<dyn Curve>`.
+/// It is also exported as `Curve1D`.
+///
+/// ```
+/// use meander::{Curve, Meander1D, UnitSinusoid};
+///
+/// let s = UnitSinusoid::new(1.0, 0.0);
+/// let c: Meander1D = meander::rand::random();
+/// let curves: Vec<Box<dyn Curve>> = vec![Box::new(s), Box::new(c.clone())];
+/// assert_eq!(curves[0].evaluate(0.5), s.evaluate(0.5));
+/// assert_eq!(curves[1].evaluate(0.5), c.evaluate(0.5));
+///
+/// fn sample<C: meander::Curve1D>(c: &C, t: f64) -> f64 {
+///     c.evaluate(t)
+/// }
+/// assert_eq!(sample(&s, 0.25), s.evaluate(0.25));
+/// assert_eq!(sample(&c, 0.25), c.evaluate(0.25));
+/// assert_eq!(sample(&0.4, 0.25), 0.4);
+/// ```
+pub trait Curve {
+    /// Find the value of the curve at a given point in time.
+    fn evaluate(&self, t: f64) -> f64;
+}
+
+impl Curve for f64 {
+    fn evaluate(&self, _t: f64) -> f64 {
+        *self
+    }
+}
+
+impl<W: Waveform> Curve for UnitSinusoid<W> {
+    fn evaluate(&self, t: f64) -> f64 {
+        UnitSinusoid::evaluate(*self, t)
+    }
+}
+
+impl<N, W> Curve for Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    fn evaluate(&self, t: f64) -> f64 {
+        Meander1D::evaluate(self, t)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<N, W> Curve for CompositeCurve<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    fn evaluate(&self, t: f64) -> f64 {
+        CompositeCurve::evaluate(self, t)
+    }
+}
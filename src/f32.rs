@@ -0,0 +1,146 @@
+//! Single-precision versions of the types in the crate root.
+//!
+//! These mirror the basic evaluation API of `UnitSinusoid`, `Meander1D` and
+//! `Meander`, but store their parameters and perform all of their math in `f32`. This is useful when the
+//! results are going to end up as `f32` anyway, for example in a shader uniform
+//! buffer.
+//!
+//! Parameters are sampled from the same ranges as their `f64` counterparts, so the
+//! output follows the same distribution.
+//!
+//! ```
+//! use meander::typenum::U3;
+//!
+//! let s64 = meander::UnitSinusoid { frequency: 3.25, phase: 0.125, ..Default::default() };
+//! let s32 = meander::f32::UnitSinusoid { frequency: 3.25, phase: 0.125 };
+//! let c64 = meander::Meander1D::<U3> { components: [s64; 3].into(), ..Default::default() };
+//! let c32 = meander::f32::Meander1D::<U3> { components: [s32; 3].into() };
+//!
+//! for i in 0..100 {
+//!     let t = i as f64 * 0.01;
+//!     assert!((c64.evaluate(t) - c32.evaluate(t as f32) as f64).abs() < 1e-5);
+//! }
+//! ```
+
+use generic_array::{GenericArray, ArrayLength};
+use generic_array::sequence::GenericSequence;
+use generic_array::typenum::U3;
+
+use rand::Rng;
+use rand::distributions::{Distribution, Standard};
+
+const PI2: f32 = 2.0 * core::f32::consts::PI;
+
+/// Represents a sinusoid that varies between 0 and 1.
+///
+/// This can be generated randomly using `rand::random()`.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSinusoid {
+    /// The number of cycles the function makes per unit time.
+    pub frequency: f32,
+    /// The location in the cycle the function is `t = 0`.
+    pub phase: f32,
+}
+
+impl Distribution<UnitSinusoid> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitSinusoid {
+        let frequency: f32 = rng.gen_range(1.0, 10.0);
+        let phase = rng.gen_range(0.0, frequency.recip());
+        UnitSinusoid { frequency, phase }
+    }
+}
+
+impl UnitSinusoid {
+    fn haversin(theta: f32) -> f32 {
+        (1.0 - crate::math::cosf(theta)) / 2.0
+    }
+    /// Find the value of the sinusoid at a given point in time.
+    pub fn evaluate(self, t: f32) -> f32 {
+        Self::haversin(PI2 * self.frequency * (t + self.phase))
+    }
+}
+
+/// Represents a curve that meanders through 1-dimensional space. Consists of `N`
+/// sinusoids whose values are averaged. By default, `N` is 3.
+///
+/// This can be generated randomly using `rand::random()`.
+#[derive(Clone, Debug)]
+pub struct Meander1D<N: ArrayLength<UnitSinusoid> = U3> {
+    /// The sinusoids whose values are averaged to produce the curve.
+    pub components: GenericArray<UnitSinusoid, N>,
+}
+
+impl<N: ArrayLength<UnitSinusoid>> Meander1D<N> {
+    /// Find the value of the curve at a given point in time.
+    pub fn evaluate(&self, t: f32) -> f32 {
+        let sum: f32 = self.components.iter().map(|s| s.evaluate(t)).sum();
+        sum / N::to_usize() as f32
+    }
+}
+
+impl<N: ArrayLength<UnitSinusoid>> Distribution<Meander1D<N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander1D<N> {
+        Meander1D {
+            components: <GenericArray<_, _> as GenericSequence<_>>::generate(|_| rng.gen()),
+        }
+    }
+}
+
+/// Represents a curve that meanders through `D`-dimensional space.
+///
+/// Each variable's curve is made of `N` sinusoids, 3 by default.
+///
+/// This can be generated randomly using `rand::random()`.
+#[derive(Clone, Debug)]
+pub struct Meander<D, N = U3>
+where
+    D: ArrayLength<Meander1D<N>>,
+    N: ArrayLength<UnitSinusoid>,
+{
+    /// Each variable is controlled by a separate 1-dimensional function defined here.
+    pub curves: GenericArray<Meander1D<N>, D>,
+}
+
+impl<D, N> Distribution<Meander<D, N>> for Standard
+where
+    D: ArrayLength<Meander1D<N>>,
+    N: ArrayLength<UnitSinusoid>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander<D, N> {
+        Meander {
+            curves: <GenericArray<_, _> as GenericSequence<_>>::generate(|_| rng.gen()),
+        }
+    }
+}
+
+impl<D, N> Meander<D, N>
+where
+    D: ArrayLength<Meander1D<N>> + ArrayLength<f32>,
+    N: ArrayLength<UnitSinusoid>,
+{
+    /// Find the value of each of the variables at a particular point in time.
+    ///
+    /// This doesn't clone the meander or allocate; use `evaluate_into` to also
+    /// reuse the output buffer.
+    pub fn evaluate(&self, t: f32) -> GenericArray<f32, D> {
+        let mut out = GenericArray::default();
+        self.evaluate_into(t, &mut out);
+        out
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// writing them into `out`.
+    pub fn evaluate_into(&self, t: f32, out: &mut GenericArray<f32, D>) {
+        for (o, c) in out.iter_mut().zip(self.curves.iter()) {
+            *o = c.evaluate(t);
+        }
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    pub fn time_steps<'a>(&'a self, dt: f32) -> impl Iterator<Item=GenericArray<f32, D>> + 'a {
+        (0u64..).map(move |i| self.evaluate(i as f32 * dt))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    /// Consumes `self`.
+    pub fn into_time_steps(self, dt: f32) -> impl Iterator<Item=GenericArray<f32, D>> {
+        (0u64..).map(move |i| self.evaluate(i as f32 * dt))
+    }
+}
@@ -6,12 +6,14 @@
 //! parameters changes a model.
 //! 
 //! The variables yielded by this crate will all have values between 0 and 1, so you
-//! should scale them to suit your purposes.
+//! should scale them to suit your purposes. (The exception is if you change the
+//! `amplitude` or `offset` of a `UnitSinusoid` yourself.)
 //!
 //! # How it Works
 //!
 //! For each variable, there is a separate function that determines its motion.
-//! This function is given by the average of three sinusoidal functions.
+//! This function is given by the average of several sinusoidal functions (three
+//! by default).
 //! 
 //! ```no_run
 //! use meander::rand;
@@ -41,103 +43,4863 @@
 //!         })
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! - `std` (enabled by default): use the standard library. Without it, this crate
+//!   is `no_std`, and the `libm` feature must be enabled to provide the
+//!   trigonometric functions it needs. This also enables `Meander::to_csv`.
+//! - `alloc` (enabled by `std`): enable `DynMeander`, which stores its curves in a
+//!   `Vec`, along with `BoxedMeander`, `CompositeCurve` and other APIs that
+//!   allocate.
+//! - `arbitrary`: implements `arbitrary::Arbitrary` for `UnitSinusoid`,
+//!   `Meander1D` and `Meander`, for generating them in fuzz tests. Frequencies and
+//!   phases are drawn from the same ranges as `rand::random()` uses, so the
+//!   results always evaluate to finite values.
+//! - `libm`: use `libm` for floating-point math when `std` is disabled.
+//! - `palette`: enable `Meander::to_srgb` and `Meander::to_hsv`, which convert
+//!   the variables of a three-dimensional meander to colors from the `palette`
+//!   crate.
+//! - `rayon`: enable `Meander::par_time_steps` and `Meander::par_evaluate_range`,
+//!   which evaluate many points in time in parallel.
+//! - `serde`: implements `Serialize` and `Deserialize` for `UnitSinusoid`,
+//!   `Meander1D`, `Meander` and `DynMeander`. Deserializing a value produces exactly the same
+//!   output as the original. This also enables the `compact` module, which
+//!   serializes meanders as nested arrays of numbers.
+//!
+//! ```
+//! # #[cfg(feature = "serde")] {
+//! use meander::typenum::U3;
+//! use meander::Meander;
+//!
+//! let m: Meander<U3> = meander::rand::random();
+//! let json = serde_json::to_string(&m).unwrap();
+//! let m2: Meander<U3> = serde_json::from_str(&json).unwrap();
+//! assert_eq!(m.evaluate(1.5), m2.evaluate(1.5));
+//!
+//! let s = m.curves[0].components[0];
+//! let json = serde_json::to_string(&s).unwrap();
+//! let s2: meander::UnitSinusoid = serde_json::from_str(&json).unwrap();
+//! assert_eq!(s.evaluate(1.5), s2.evaluate(1.5));
+//!
+//! let c = m.curves[1].clone();
+//! let json = serde_json::to_string(&c).unwrap();
+//! let c2: meander::Meander1D = serde_json::from_str(&json).unwrap();
+//! assert_eq!(c.evaluate(1.5), c2.evaluate(1.5));
+//! # }
+//! ```
+//!
+//! # Thread Safety
+//!
+//! `UnitSinusoid`, `Meander1D`, `Meander`, `ConstMeander`, `DynMeander` and
+//! `CompositeCurve` are all `Send` and `Sync` as long as their waveform is (which
+//! all of the waveforms in this crate are). Evaluating them only reads their
+//! parameters, without cloning, allocating or using any interior mutability, so a
+//! single meander can be shared between threads and evaluated from all of them at
+//! once. The same goes for their single-precision versions in `meander::f32`.
+//! `BoxedMeander` is neither `Send` nor `Sync`, since the curves it holds might
+//! not be.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use std::thread;
+//! use meander::typenum::U3;
+//! use meander::Meander;
+//!
+//! let m: Arc<Meander<U3>> = Arc::new(meander::rand::random());
+//! let handles: Vec<_> = (0..4).map(|i| {
+//!     let m = Arc::clone(&m);
+//!     thread::spawn(move || {
+//!         let t = i as f64 * 0.75;
+//!         (t, m.evaluate(t))
+//!     })
+//! }).collect();
+//! for handle in handles {
+//!     let (t, values) = handle.join().unwrap();
+//!     assert_eq!(values, m.evaluate(t));
+//! }
+//!
+//! fn assert_send_sync<T: Send + Sync>() {}
+//! assert_send_sync::<meander::UnitSinusoid<meander::Triangle>>();
+//! assert_send_sync::<meander::Meander1D>();
+//! assert_send_sync::<meander::ConstMeander<3>>();
+//! assert_send_sync::<meander::DynMeander>();
+//! assert_send_sync::<meander::CompositeCurve>();
+//! assert_send_sync::<meander::f32::Meander<U3>>();
+//! ```
 
 #![deny(missing_docs)]
+#![no_std]
+
+#[cfg(all(not(feature = "std"), not(feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 pub use rand;
 pub use generic_array;
 pub use generic_array::typenum;
+#[cfg(feature = "palette")]
+pub use palette;
+
+pub mod f32;
+#[cfg(feature = "serde")]
+pub mod compact;
+mod curve;
+mod math;
+mod period;
+mod waveform;
+
+pub use curve::Curve;
+pub use curve::Curve as Curve1D;
+pub use waveform::{Waveform, Haversin, Triangle, Smoothstep};
 
 use generic_array::{GenericArray, ArrayLength};
+use generic_array::typenum::{Sum, U2, U3};
 use generic_array::functional::FunctionalSequence;
-use generic_array::sequence::GenericSequence;
+use generic_array::sequence::{Concat, GenericSequence};
 
-use rand::Rng;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Add;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 use rand::distributions::{Distribution, Standard};
 
-const PI2: f64 = 2.0 * std::f64::consts::PI;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+const PI2: f64 = 2.0 * core::f64::consts::PI;
 
-/// Represents a sinusoid that varies between 0 and 1.
+/// Represents a sinusoid that varies between `offset` and `offset + amplitude`.
+/// By default, these are 0 and 1.
+///
+/// The shape of each cycle is determined by `W`, which is `Haversin` by default.
 ///
 /// This can be generated randomly using `rand::random()`.
+///
+/// ```
+/// use meander::UnitSinusoid;
+///
+/// let s: UnitSinusoid = UnitSinusoid { amplitude: 0.5, offset: 0.25, ..Default::default() };
+/// assert_eq!(s.evaluate(0.0), 0.25);
+/// assert_eq!(s.evaluate(0.5), 0.75);
+///
+/// let wide: UnitSinusoid = UnitSinusoid { amplitude: 2.0, offset: -0.5, ..Default::default() };
+/// assert_eq!(wide.evaluate(0.0), -0.5);
+/// assert_eq!(wide.evaluate(0.5), 1.5);
+/// ```
+///
+/// Equality and hashing compare the bit patterns of the parameters, as given by
+/// `f64::to_bits`. This means that `0.0` and `-0.0` are considered different, and
+/// a `NaN` is equal to another `NaN` with the same bits. This makes it possible to
+/// use sinusoids (and the curves and meanders made from them) as keys in a
+/// `HashMap`:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use meander::typenum::U3;
+/// use meander::Meander;
+///
+/// let mut renders = HashMap::new();
+/// renders.insert(Meander::<U3>::from_seed(7), "first");
+/// renders.insert(Meander::<U3>::from_seed(7), "second");
+/// assert_eq!(renders.len(), 1);
+/// assert_eq!(renders[&Meander::from_seed(7)], "second");
+/// ```
+///
+/// The frequency should be positive, which `validate` checks. Nothing stops the
+/// fields from being set to other values, and `evaluate` still gives a defined
+/// result for them: a sinusoid with frequency 0 never leaves the start of its
+/// cycle, so it stays at `offset` whatever its phase, and one with a negative
+/// frequency runs through its cycle backward (which, for a symmetric waveform
+/// like `Haversin`, is the same as using the positive frequency). However,
+/// functions that sample a phase from `0.0..frequency.recip()`, such as
+/// `Meander::rephase`, panic if the frequency isn't positive.
 #[derive(Clone, Copy, Debug)]
-pub struct UnitSinusoid {
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnitSinusoid<W = Haversin> {
     /// The number of cycles the function makes per unit time.
     pub frequency: f64,
     /// The location in the cycle the function is `t = 0`.
     pub phase: f64,
+    /// The difference between the largest and smallest values of the function.
+    pub amplitude: f64,
+    /// The smallest value of the function.
+    pub offset: f64,
+    /// How much the sinusoid contributes to the value of a `Meander1D` relative to
+    /// the curve's other components. This is 1 by default, and doesn't affect the
+    /// value of the sinusoid itself.
+    #[cfg_attr(feature = "serde", serde(default = "default_weight"))]
+    pub weight: f64,
+    /// The shape of each cycle of the function.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub waveform: W,
+}
+
+impl<W> UnitSinusoid<W> {
+    /// Find the length of a single cycle of the sinusoid, `1.0 / frequency`.
+    ///
+    /// ```
+    /// use meander::UnitSinusoid;
+    ///
+    /// assert_eq!(UnitSinusoid::new(4.0, 0.0).period(), 0.25);
+    /// ```
+    pub fn period(&self) -> f64 {
+        self.frequency.recip()
+    }
+    /// Check that the frequency is positive and finite and that the other
+    /// parameters are all finite, returning a description of the first problem
+    /// found if not.
+    ///
+    /// ```
+    /// use meander::UnitSinusoid;
+    ///
+    /// assert_eq!(UnitSinusoid::new(2.0, 0.1).validate(), Ok(()));
+    ///
+    /// let still = UnitSinusoid { frequency: 0.0, offset: 0.25, ..UnitSinusoid::new(1.0, 0.3) };
+    /// assert!(still.validate().is_err());
+    /// assert_eq!(still.evaluate(0.0), 0.25);
+    /// assert_eq!(still.evaluate(17.5), 0.25);
+    ///
+    /// let backward = UnitSinusoid { frequency: -2.0, ..UnitSinusoid::new(1.0, 0.3) };
+    /// let forward = UnitSinusoid::new(2.0, 0.3);
+    /// assert!(backward.validate().is_err());
+    /// for &t in &[0.0, 0.1, 1.7] {
+    ///     assert!((backward.evaluate(t) - forward.evaluate(t)).abs() < 1e-12);
+    /// }
+    ///
+    /// let broken = UnitSinusoid { amplitude: f64::NAN, ..forward };
+    /// assert!(broken.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if !self.frequency.is_finite() {
+            Err("frequency must be finite")
+        } else if self.frequency <= 0.0 {
+            Err("frequency must be positive")
+        } else if !self.phase.is_finite() {
+            Err("phase must be finite")
+        } else if !self.amplitude.is_finite() {
+            Err("amplitude must be finite")
+        } else if !self.offset.is_finite() {
+            Err("offset must be finite")
+        } else if !self.weight.is_finite() {
+            Err("weight must be finite")
+        } else {
+            Ok(())
+        }
+    }
+    fn param_bits(&self) -> [u64; 5] {
+        [
+            self.frequency.to_bits(),
+            self.phase.to_bits(),
+            self.amplitude.to_bits(),
+            self.offset.to_bits(),
+            self.weight.to_bits(),
+        ]
+    }
+}
+
+#[cfg(feature = "serde")]
+fn default_weight() -> f64 {
+    1.0
+}
+
+fn default_envelope() -> UnitSinusoid {
+    UnitSinusoid::constant(1.0)
+}
+
+fn is_default_envelope(envelope: &UnitSinusoid) -> bool {
+    *envelope == default_envelope()
+}
+
+impl<W: PartialEq> PartialEq for UnitSinusoid<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.param_bits() == other.param_bits() && self.waveform == other.waveform
+    }
+}
+
+impl<W: Eq> Eq for UnitSinusoid<W> {}
+
+impl<W: Hash> Hash for UnitSinusoid<W> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.param_bits().hash(state);
+        self.waveform.hash(state);
+    }
+}
+
+impl<W: Default> Default for UnitSinusoid<W> {
+    /// A sinusoid with frequency 1, phase 0, amplitude 1, offset 0 and weight 1.
+    fn default() -> Self {
+        UnitSinusoid {
+            frequency: 1.0,
+            phase: 0.0,
+            amplitude: 1.0,
+            offset: 0.0,
+            weight: 1.0,
+            waveform: W::default(),
+        }
+    }
+}
+
+impl<W: Default> Distribution<UnitSinusoid<W>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitSinusoid<W> {
+        UnitSinusoidDist::default().sample(rng)
+    }
+}
+
+/// A distribution over `UnitSinusoid`s whose frequencies lie in a given range.
+///
+/// The frequency is sampled uniformly from `freq_min..freq_max`, and the phase is
+/// sampled uniformly from `0.0..frequency.recip()`. `freq_min` must be positive.
+///
+/// The default range is `1.0..10.0`, which is what `rand::random()` uses.
+///
+/// ```
+/// use meander::rand::{self, Rng};
+/// use meander::{UnitSinusoid, UnitSinusoidDist};
+///
+/// let dist = UnitSinusoidDist { freq_min: 0.2, freq_max: 2.0 };
+/// let mut rng = rand::thread_rng();
+/// for _ in 0..1000 {
+///     let s: UnitSinusoid = rng.sample(dist);
+///     assert!(0.2 <= s.frequency && s.frequency < 2.0);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSinusoidDist {
+    /// The smallest frequency that can be generated.
+    pub freq_min: f64,
+    /// The upper bound (exclusive) of the frequencies that can be generated.
+    pub freq_max: f64,
+}
+
+impl Default for UnitSinusoidDist {
+    fn default() -> Self {
+        UnitSinusoidDist { freq_min: 1.0, freq_max: 10.0 }
+    }
 }
 
-impl Distribution<UnitSinusoid> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitSinusoid {
-        let frequency: f64 = rng.gen_range(1.0, 10.0);
+impl<W: Default> Distribution<UnitSinusoid<W>> for UnitSinusoidDist {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitSinusoid<W> {
+        let frequency: f64 = rng.gen_range(self.freq_min, self.freq_max);
         let phase = rng.gen_range(0.0, frequency.recip());
-        UnitSinusoid { frequency, phase }
+        UnitSinusoid { frequency, phase, ..Default::default() }
     }
 }
 
-impl UnitSinusoid {
-    fn haversin(theta: f64) -> f64 {
-        (1.0 - theta.cos()) / 2.0
+impl<W: Default> UnitSinusoid<W> {
+    /// Generate `n` random sinusoids, as if by calling `rng.gen()` `n` times.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::rand::{Rng, SeedableRng};
+    /// use meander::rand::rngs::StdRng;
+    /// use meander::UnitSinusoid;
+    ///
+    /// let batch: Vec<UnitSinusoid> = UnitSinusoid::sample_many(&mut StdRng::seed_from_u64(1), 100);
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let single: Vec<UnitSinusoid> = (0..100).map(|_| rng.gen()).collect();
+    /// assert_eq!(batch, single);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sample_many<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<Self> {
+        let mut out = Vec::with_capacity(n);
+        out.extend((0..n).map(|_| rng.sample(Standard)));
+        out
     }
+}
+
+impl<W: Waveform> UnitSinusoid<W> {
     /// Find the value of the sinusoid at a given point in time.
+    ///
+    /// `t` may be negative, in which case this is the value the sinusoid had at a
+    /// time before `t = 0`; the sinusoid is periodic in both directions.
     pub fn evaluate(self, t: f64) -> f64 {
-        Self::haversin(PI2 * self.frequency * (t + self.phase))
+        let cycles = self.frequency * (t + self.phase);
+        self.offset + self.amplitude * self.waveform.value(cycles - math::floor(cycles))
+    }
+}
+
+impl<W: PartialEq> UnitSinusoid<W> {
+    /// Check whether two sinusoids have the same waveform and have parameters
+    /// that differ by at most `epsilon`.
+    ///
+    /// ```
+    /// use meander::UnitSinusoid;
+    ///
+    /// let a = UnitSinusoid::new(2.0, 0.1);
+    /// let b = UnitSinusoid::new(2.0, 0.1 + 1e-12);
+    /// assert_ne!(a, b);
+    /// assert!(a.approx_eq(&b, 1e-9));
+    /// assert!(!a.approx_eq(&UnitSinusoid::new(2.1, 0.1), 1e-9));
+    /// assert_eq!(a, UnitSinusoid::new(2.0, 0.1));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (self.frequency - other.frequency).abs() <= epsilon
+            && (self.phase - other.phase).abs() <= epsilon
+            && (self.amplitude - other.amplitude).abs() <= epsilon
+            && (self.offset - other.offset).abs() <= epsilon
+            && (self.weight - other.weight).abs() <= epsilon
+            && self.waveform == other.waveform
+    }
+}
+
+/// The phase of a sinusoid, in one of several units.
+///
+/// A sinusoid's value at time `t` is `offset + amplitude * haversin(2π *
+/// frequency * (t + phase))`, so the `phase` field of `UnitSinusoid` is measured
+/// in units of time: the sinusoid behaves as though `phase` more time had passed
+/// than actually has. How far through its cycle that puts the sinusoid depends on
+/// its frequency, which makes it easy to get wrong when setting the field by hand.
+/// `Phase` lets the phase be given in whichever unit is most natural, and
+/// `UnitSinusoid::with_phase` converts it into a time offset.
+///
+/// ```
+/// use core::f64::consts::PI;
+/// use meander::{Phase, UnitSinusoid};
+///
+/// // A quarter of a cycle in, a haversin is halfway up.
+/// let s = UnitSinusoid::with_phase(4.0, Phase::turns(0.25));
+/// assert_eq!(s.phase, 0.0625);
+/// assert!((s.evaluate(0.0) - 0.5).abs() < 1e-12);
+///
+/// // Half a cycle (π radians) in, it is at its peak.
+/// let s = UnitSinusoid::with_phase(4.0, Phase::radians(PI));
+/// assert!((s.evaluate(0.0) - 1.0).abs() < 1e-12);
+///
+/// // Starting 0.125 time units ahead of a sinusoid with phase 0 is the same as
+/// // sampling that sinusoid 0.125 time units later.
+/// let s = UnitSinusoid::with_phase(4.0, Phase::time_offset(0.125));
+/// let plain = UnitSinusoid::new(4.0, 0.0);
+/// assert!((s.evaluate(0.0) - 1.0).abs() < 1e-12);
+/// for &t in &[0.0, 0.3, 1.7] {
+///     assert!((s.evaluate(t) - plain.evaluate(t + 0.125)).abs() < 1e-12);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Phase(PhaseUnit);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PhaseUnit {
+    Turns(f64),
+    Time(f64),
+}
+
+impl Phase {
+    /// A phase of `turns` complete cycles, so that `Phase::turns(0.5)` starts the
+    /// sinusoid halfway through its cycle whatever its frequency. Becomes a time
+    /// offset of `turns / frequency`.
+    pub fn turns(turns: f64) -> Self {
+        Phase(PhaseUnit::Turns(turns))
+    }
+    /// A phase of `radians`, where a complete cycle is `2π` radians. Becomes a time
+    /// offset of `radians / (2π * frequency)`.
+    pub fn radians(radians: f64) -> Self {
+        Phase(PhaseUnit::Turns(radians / PI2))
+    }
+    /// A phase of `time` units of time, which is how the `phase` field of
+    /// `UnitSinusoid` is measured. This is used unchanged whatever the frequency.
+    pub fn time_offset(time: f64) -> Self {
+        Phase(PhaseUnit::Time(time))
+    }
+    /// Find the time offset this phase corresponds to for a sinusoid with the given
+    /// frequency, which is the value to store in `UnitSinusoid::phase`.
+    ///
+    /// ```
+    /// use meander::Phase;
+    ///
+    /// assert_eq!(Phase::turns(0.5).to_time_offset(2.0), 0.25);
+    /// assert_eq!(Phase::time_offset(0.5).to_time_offset(2.0), 0.5);
+    /// ```
+    pub fn to_time_offset(self, frequency: f64) -> f64 {
+        match self.0 {
+            PhaseUnit::Turns(turns) => turns / frequency,
+            PhaseUnit::Time(time) => time,
+        }
+    }
+}
+
+impl UnitSinusoid<Haversin> {
+    /// Create a sinusoid with the given frequency and phase, amplitude 1, offset 0
+    /// and weight 1.
+    ///
+    /// Panics if `frequency` is not positive.
+    ///
+    /// ```should_panic
+    /// meander::UnitSinusoid::new(0.0, 0.5);
+    /// ```
+    pub fn new(frequency: f64, phase: f64) -> Self {
+        assert!(frequency > 0.0, "frequency must be positive");
+        UnitSinusoid { frequency, phase, ..Default::default() }
+    }
+    /// Create a sinusoid with the given frequency and phase, amplitude 1, offset 0
+    /// and weight 1, like `new`, but with the phase given in any of the units
+    /// supported by `Phase`.
+    ///
+    /// Panics if `frequency` is not positive.
+    ///
+    /// ```
+    /// use meander::{Phase, UnitSinusoid};
+    ///
+    /// assert_eq!(UnitSinusoid::with_phase(2.0, Phase::turns(0.5)), UnitSinusoid::new(2.0, 0.25));
+    /// ```
+    pub fn with_phase(frequency: f64, phase: Phase) -> Self {
+        Self::new(frequency, phase.to_time_offset(frequency))
+    }
+    /// Create a sinusoid that stays at `value`, with amplitude 0 and offset
+    /// `value`. Its frequency is 1 and its phase is 0, although they don't affect
+    /// its value.
+    ///
+    /// ```
+    /// use meander::UnitSinusoid;
+    ///
+    /// let s = UnitSinusoid::constant(0.3);
+    /// assert_eq!(s.evaluate(0.0), 0.3);
+    /// assert_eq!(s.evaluate(12.7), 0.3);
+    /// ```
+    pub fn constant(value: f64) -> Self {
+        UnitSinusoid { frequency: 1.0, phase: 0.0, amplitude: 0.0, offset: value, ..Default::default() }
+    }
+    /// Find the rate of change of the sinusoid at a given point in time.
+    ///
+    /// This is computed analytically, so it is exact regardless of how coarsely
+    /// the sinusoid is being sampled.
+    ///
+    /// ```
+    /// use meander::UnitSinusoid;
+    ///
+    /// let s = UnitSinusoid { frequency: 7.3, phase: 0.04, ..Default::default() };
+    /// let h = 1e-6;
+    /// for &t in &[0.0, 0.1, 0.25, 3.9, 100.0] {
+    ///     let approx = (s.evaluate(t + h) - s.evaluate(t - h)) / (2.0 * h);
+    ///     assert!((s.derivative(t) - approx).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn derivative(self, t: f64) -> f64 {
+        let theta = PI2 * self.frequency * (t + self.phase);
+        self.amplitude * PI2 * self.frequency * math::sin(theta) / 2.0
+    }
+    /// Find both the value and the rate of change of the sinusoid at a given point
+    /// in time, computing the angle only once.
+    ///
+    /// The value is exactly `evaluate(t)`, and the rate of change is `derivative(t)`
+    /// up to rounding error.
+    ///
+    /// ```
+    /// use meander::UnitSinusoid;
+    ///
+    /// let s: UnitSinusoid = meander::rand::random();
+    /// for &t in &[0.0, 0.1, 0.25, 3.9, 100.0] {
+    ///     let (value, slope) = s.evaluate_with_derivative(t);
+    ///     assert_eq!(value, s.evaluate(t));
+    ///     assert!((slope - s.derivative(t)).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn evaluate_with_derivative(self, t: f64) -> (f64, f64) {
+        let cycles = self.frequency * (t + self.phase);
+        let theta = PI2 * (cycles - math::floor(cycles));
+        let value = self.offset + self.amplitude * ((1.0 - math::cos(theta)) / 2.0);
+        let slope = self.amplitude * PI2 * self.frequency * math::sin(theta) / 2.0;
+        (value, slope)
+    }
+    /// Find the long-run average value of the sinusoid, which is halfway between
+    /// `offset` and `offset + amplitude` regardless of the frequency and phase.
+    ///
+    /// ```
+    /// use meander::UnitSinusoid;
+    ///
+    /// let s: UnitSinusoid = meander::rand::random();
+    /// assert_eq!(s.mean(), 0.5);
+    ///
+    /// let n = 100_000;
+    /// let numerical: f64 = (0..n).map(|i| s.evaluate(i as f64 * 0.01)).sum::<f64>() / n as f64;
+    /// assert!((numerical - s.mean()).abs() < 1e-3);
+    /// ```
+    pub fn mean(&self) -> f64 {
+        self.offset + self.amplitude / 2.0
+    }
+    /// Find the average value of the sinusoid between `t0` and `t1`.
+    ///
+    /// This is computed analytically. If `t0 == t1`, it is the value at `t0`.
+    pub fn average_over(self, t0: f64, t1: f64) -> f64 {
+        if t0 == t1 {
+            return self.evaluate(t0);
+        }
+        let omega = PI2 * self.frequency;
+        let sin0 = math::sin(omega * (t0 + self.phase));
+        let sin1 = math::sin(omega * (t1 + self.phase));
+        let mean_haversin = 0.5 - (sin1 - sin0) / (2.0 * omega * (t1 - t0));
+        self.offset + self.amplitude * mean_haversin
     }
 }
 
-/// Represents a curve that meanders through 1-dimensional space. Consists of 3
-/// sinusoids whose values are averaged.
+/// Represents a curve that meanders through 1-dimensional space. Consists of `N`
+/// sinusoids whose values are averaged. By default, `N` is 3, and the sinusoids
+/// have the `Haversin` waveform.
+///
+/// The average is weighted by the `weight` of each sinusoid, which is 1 by
+/// default, so that the value of the curve is `Σ wᵢvᵢ / Σ wᵢ`. The weights must
+/// not add up to 0.
+///
+/// The curve stays between 0 and 1 only as long as its components do, so this is
+/// no longer guaranteed if any of their amplitudes or offsets have been changed.
+///
+/// The average is then multiplied by the value of the `envelope`, so a slow
+/// envelope makes the curve swell and fade over a long timescale. By default the
+/// envelope is constant at 1.
 ///
 /// This can be generated randomly using `rand::random()`.
-#[derive(Clone, Copy, Debug)]
-pub struct Meander1D(pub UnitSinusoid, pub UnitSinusoid, pub UnitSinusoid);
+///
+/// A curve with a single component is just that component:
+///
+/// ```
+/// use meander::typenum::U1;
+/// use meander::{Meander1D, UnitSinusoid};
+///
+/// let s = UnitSinusoid { frequency: 2.5, phase: 0.1, ..Default::default() };
+/// let curve = Meander1D::<U1> { components: [s].into(), ..Default::default() };
+/// for &t in &[0.0, 0.3, 1.7, 42.0] {
+///     assert_eq!(curve.evaluate(t), s.evaluate(t));
+/// }
+/// ```
+///
+/// The `Debug` representation lists the waveform, frequency and phase of each
+/// component, along with its amplitude, offset and weight if they aren't the
+/// defaults, followed by the envelope if it isn't the default:
+///
+/// ```
+/// use meander::{Meander1D, UnitSinusoid};
+///
+/// let c = Meander1D::new(
+///     UnitSinusoid::new(2.5, 0.1),
+///     UnitSinusoid::new(3.0, 0.0),
+///     UnitSinusoid { amplitude: 0.5, ..UnitSinusoid::new(7.0, 0.125) },
+/// );
+/// assert_eq!(
+///     format!("{:?}", c),
+///     "Meander1D [Haversin(freq=2.5, phase=0.1), Haversin(freq=3.0, phase=0.0), \
+///      Haversin(freq=7.0, phase=0.125, amplitude=0.5)]",
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(serialize = "", deserialize = "W: Default")),
+)]
+pub struct Meander1D<N = U3, W = Haversin>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// The sinusoids whose values are averaged to produce the curve.
+    pub components: GenericArray<UnitSinusoid<W>, N>,
+    /// A sinusoid that the average of the components is multiplied by. By default
+    /// this is `UnitSinusoid::constant(1.0)`, which leaves it as it is.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default = "default_envelope", skip_serializing_if = "is_default_envelope"),
+    )]
+    pub envelope: UnitSinusoid,
+}
+
+impl<N, W> fmt::Debug for Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Meander1D ")?;
+        f.debug_list().entries(self.components.iter().map(ComponentDebug)).finish()?;
+        if !is_default_envelope(&self.envelope) {
+            write!(f, " with envelope {:?}", ComponentDebug(&self.envelope))?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a component of a `Meander1D` compactly.
+struct ComponentDebug<'a, W>(&'a UnitSinusoid<W>);
+
+impl<'a, W: fmt::Debug> fmt::Debug for ComponentDebug<'a, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self.0;
+        write!(f, "{:?}(freq={:?}, phase={:?}", s.waveform, s.frequency, s.phase)?;
+        if s.amplitude != 1.0 {
+            write!(f, ", amplitude={:?}", s.amplitude)?;
+        }
+        if s.offset != 0.0 {
+            write!(f, ", offset={:?}", s.offset)?;
+        }
+        if s.weight != 1.0 {
+            write!(f, ", weight={:?}", s.weight)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl<N, W> Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// Create a curve from its components, with the given weights.
+    ///
+    /// This sets the `weight` of each component, so that the curve is a weighted
+    /// average of them. A slow component with a large weight can set the overall
+    /// trend of the curve while faster ones with small weights add texture.
+    ///
+    /// ```
+    /// use meander::generic_array::arr;
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let components = arr![UnitSinusoid;
+    ///     UnitSinusoid::new(0.5, 0.1),
+    ///     UnitSinusoid::new(3.0, 0.2),
+    ///     UnitSinusoid::new(7.0, 0.3),
+    /// ];
+    /// let c = Meander1D::weighted(components, arr![f64; 1.0, 0.0, 0.0]);
+    /// for &t in &[0.0, 0.4, 1.7, 25.0] {
+    ///     assert_eq!(c.evaluate(t), components[0].evaluate(t));
+    /// }
+    ///
+    /// let c = Meander1D::weighted(components, arr![f64; 2.0, 1.0, 1.0]);
+    /// let v: Vec<f64> = components.iter().map(|s| s.evaluate(0.4)).collect();
+    /// assert!((c.evaluate(0.4) - (2.0 * v[0] + v[1] + v[2]) / 4.0).abs() < 1e-12);
+    /// ```
+    pub fn weighted(
+        mut components: GenericArray<UnitSinusoid<W>, N>,
+        weights: GenericArray<f64, N>,
+    ) -> Self
+    where
+        N: ArrayLength<f64>,
+    {
+        for (s, &w) in components.iter_mut().zip(weights.iter()) {
+            s.weight = w;
+        }
+        Meander1D { components, envelope: default_envelope() }
+    }
+    /// Find the sum of the weights of the components.
+    fn total_weight(&self) -> f64 {
+        self.components.iter().map(|s| s.weight).sum()
+    }
+    /// Find the weighted average of some values, one for each component.
+    fn weighted_mean<I: Iterator<Item=f64>>(&self, values: I) -> f64 {
+        let sum: f64 = values.zip(self.components.iter()).map(|(v, s)| s.weight * v).sum();
+        sum / self.total_weight()
+    }
+}
+
+/// A `Meander1D` with three components, as used by `Meander` by default.
+pub type Meander1D3 = Meander1D<U3>;
+
+impl<W> Meander1D<U3, W> {
+    /// Create a curve from three sinusoids.
+    pub fn new(a: UnitSinusoid<W>, b: UnitSinusoid<W>, c: UnitSinusoid<W>) -> Self {
+        Meander1D { components: [a, b, c].into(), envelope: default_envelope() }
+    }
+    /// Return copies of the three component sinusoids of the curve.
+    ///
+    /// The curve's value is the weighted average of these multiplied by its
+    /// `envelope`, which is separate and isn't included here. For a curve with the
+    /// default envelope, the average is the value itself.
+    ///
+    /// ```
+    /// use meander::Meander1D;
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let [a, b, d] = c.components();
+    /// let t = 1.25;
+    /// let mean = (a.evaluate(t) + b.evaluate(t) + d.evaluate(t)) / 3.0;
+    /// assert!((c.evaluate(t) - mean).abs() < 1e-12);
+    /// ```
+    pub fn components(&self) -> [UnitSinusoid<W>; 3]
+    where
+        W: Copy,
+    {
+        [self.components[0], self.components[1], self.components[2]]
+    }
+}
+
+impl<N, W> Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// Find the frequency that best characterizes how fast the curve moves.
+    ///
+    /// This is the frequency of the component that contributes the most to the
+    /// curve, which is the one with the largest absolute `weight * amplitude`.
+    /// If several components are tied (as they are by default), the highest of
+    /// their frequencies is used, since the fastest component is the one that
+    /// stands out most.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c = Meander1D::new(
+    ///     UnitSinusoid::new(1.0, 0.0),
+    ///     UnitSinusoid::new(8.0, 0.0),
+    ///     UnitSinusoid::new(1.5, 0.0),
+    /// );
+    /// assert_eq!(c.dominant_frequency(), 8.0);
+    /// ```
+    pub fn dominant_frequency(&self) -> f64 {
+        let mut best = &self.components[0];
+        for s in self.components.iter().skip(1) {
+            let (a, b) = ((s.weight * s.amplitude).abs(), (best.weight * best.amplitude).abs());
+            if a > b || (a == b && s.frequency > best.frequency) {
+                best = s;
+            }
+        }
+        best.frequency
+    }
+    /// Check whether the frequencies of the components are all less than
+    /// `threshold` apart, which makes the curve look like a single sinusoid.
+    ///
+    /// For curves sampled from the default range of `1.0..10.0`, a threshold of
+    /// `0.5` catches the curves whose motion is closest to a single sinusoid, which
+    /// are about 1% of three-component curves.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c = Meander1D::new(
+    ///     UnitSinusoid::new(2.0, 0.0),
+    ///     UnitSinusoid::new(2.1, 0.3),
+    ///     UnitSinusoid::new(2.3, 0.1),
+    /// );
+    /// assert!(c.is_degenerate(0.5));
+    /// assert!(!c.is_degenerate(0.2));
+    /// ```
+    pub fn is_degenerate(&self, threshold: f64) -> bool {
+        let (lo, hi) = self.components.iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), s| {
+                (lo.min(s.frequency), hi.max(s.frequency))
+            });
+        hi - lo < threshold
+    }
+    /// Find the smallest positive time after which the curve repeats itself.
+    ///
+    /// This is `None` unless the frequencies of the components are all rational
+    /// multiples of each other. Each frequency ratio is treated as rational if it
+    /// is within a relative error of `1e-12` of a fraction whose denominator is at
+    /// most 10,000.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c = Meander1D::new(
+    ///     UnitSinusoid::new(2.0, 0.0),
+    ///     UnitSinusoid::new(3.0, 0.1),
+    ///     UnitSinusoid::new(2.0, 0.2),
+    /// );
+    /// assert!((c.period().unwrap() - 1.0).abs() < 1e-12);
+    ///
+    /// let c = Meander1D::new(
+    ///     UnitSinusoid::new(1.0, 0.0),
+    ///     UnitSinusoid::new(2f64.sqrt(), 0.0),
+    ///     UnitSinusoid::new(2.0, 0.0),
+    /// );
+    /// assert_eq!(c.period(), None);
+    /// ```
+    pub fn period(&self) -> Option<f64> {
+        period::common_period(self.frequencies())
+    }
+    /// Return the frequencies of the components, followed by that of the envelope
+    /// unless it is constant.
+    fn frequencies(&self) -> impl Iterator<Item=f64> + '_ {
+        let envelope = Some(self.envelope.frequency).filter(|_| self.envelope.amplitude != 0.0);
+        self.components.iter().map(|s| s.frequency).chain(envelope)
+    }
+    /// Find the value of the envelope at a given point in time.
+    fn envelope_value(&self, t: f64) -> f64 {
+        if self.envelope.amplitude == 0.0 {
+            self.envelope.offset
+        } else {
+            self.envelope.evaluate(t)
+        }
+    }
+}
 
-impl Meander1D {
+impl<N, W> Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
     /// Find the value of the curve at a given point in time.
-    pub fn evaluate(self, t: f64) -> f64 {
-        ( (self.0).evaluate(t)
-        + (self.1).evaluate(t)
-        + (self.2).evaluate(t)
-        ) / 3.0
+    pub fn evaluate(&self, t: f64) -> f64 {
+        self.weighted_mean(self.components.iter().map(|s| s.evaluate(t))) * self.envelope_value(t)
+    }
+    /// Find the values of the curve at `out.len()` points in time, starting at `t0`
+    /// and spaced `dt` apart, writing them into `out`.
+    ///
+    /// This gives the same values as calling `evaluate` at each point in time, but
+    /// loops over the components on the outside and over the points in time on the
+    /// inside, unrolled by hand to 4 points per iteration. Each point is still
+    /// evaluated separately, with no SIMD and no shared trigonometry, so whether
+    /// this is any faster than calling `evaluate` in a loop depends on the target
+    /// and the compiler. The `evaluate_block` benchmark in `benches/evaluate.rs`
+    /// compares the two.
+    ///
+    /// ```
+    /// use meander::Meander1D;
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let mut out = [0.0; 1003];
+    /// c.evaluate_block(2.0, 0.01, &mut out);
+    /// for (i, &v) in out.iter().enumerate() {
+    ///     assert!((v - c.evaluate(2.0 + i as f64 * 0.01)).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn evaluate_block(&self, t0: f64, dt: f64, out: &mut [f64]) {
+        for o in out.iter_mut() {
+            *o = 0.0;
+        }
+        for s in self.components.iter() {
+            let mut chunks = out.chunks_exact_mut(4);
+            let mut i = 0;
+            for chunk in &mut chunks {
+                let t = [0, 1, 2, 3].map(|k| t0 + (i + k) as f64 * dt);
+                chunk[0] += s.weight * s.evaluate(t[0]);
+                chunk[1] += s.weight * s.evaluate(t[1]);
+                chunk[2] += s.weight * s.evaluate(t[2]);
+                chunk[3] += s.weight * s.evaluate(t[3]);
+                i += 4;
+            }
+            for (k, o) in chunks.into_remainder().iter_mut().enumerate() {
+                *o += s.weight * s.evaluate(t0 + (i + k) as f64 * dt);
+            }
+        }
+        let total = self.total_weight();
+        for o in out.iter_mut() {
+            *o /= total;
+        }
+        for (i, o) in out.iter_mut().enumerate() {
+            *o *= self.envelope_value(t0 + i as f64 * dt);
+        }
+    }
+    /// The length of time over which the curve is sampled to estimate things about
+    /// its long-run behavior: the period of the curve if it is periodic, or else
+    /// 10 cycles of its slowest component.
+    fn horizon(&self) -> f64 {
+        period::common_period(self.frequencies())
+            .unwrap_or_else(|| {
+                let slowest = self.frequencies()
+                    .map(f64::abs)
+                    .fold(f64::INFINITY, f64::min);
+                if slowest > 0.0 && slowest.is_finite() { 10.0 / slowest } else { 1.0 }
+            })
+    }
+    /// Estimate the smallest and largest values the curve takes.
+    ///
+    /// The curve is sampled at `samples` evenly spaced points over one period if it
+    /// is periodic, or else over 10 cycles of its slowest component. Since this is
+    /// only based on sampling, the true range may be slightly wider, especially when
+    /// `samples` is small.
+    ///
+    /// ```
+    /// use meander::typenum::U1;
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let (min, max) = c.estimate_range(10_000);
+    /// assert!(0.0 <= min && min <= max && max <= 1.0);
+    ///
+    /// let single = Meander1D::<U1> { components: [UnitSinusoid::new(3.0, 0.1)].into(), ..Default::default() };
+    /// let (min, max) = single.estimate_range(10_000);
+    /// assert!(min < 1e-6 && max > 1.0 - 1e-6);
+    /// ```
+    pub fn estimate_range(&self, samples: usize) -> (f64, f64) {
+        let dt = self.horizon() / samples as f64;
+        (0..samples)
+            .map(|i| self.evaluate(i as f64 * dt))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)))
+    }
+    /// Score how evenly the curve covers the interval `[0, 1]`, from 0 (worst) to 1
+    /// (best).
+    ///
+    /// The curve is sampled at `samples` points in the same way as by
+    /// `estimate_range`, and `[0, 1]` is split into 10 equal bins. The score is the
+    /// fraction of bins that contain at least 1% of the samples. Values outside
+    /// `[0, 1]` count towards the nearest bin.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let wide = UnitSinusoid::new(1.0, 0.0);
+    /// let c = Meander1D::new(wide, wide, wide);
+    /// assert_eq!(c.coverage_score(1000), 1.0);
+    ///
+    /// let narrow = UnitSinusoid { amplitude: 0.05, offset: 0.5, ..wide };
+    /// let c = Meander1D::new(narrow, narrow, narrow);
+    /// assert!(c.coverage_score(1000) <= 0.2);
+    /// ```
+    pub fn coverage_score(&self, samples: usize) -> f64 {
+        const BINS: usize = 10;
+        let mut counts = [0usize; BINS];
+        let dt = self.horizon() / samples as f64;
+        for i in 0..samples {
+            let v = self.evaluate(i as f64 * dt).clamp(0.0, 1.0);
+            counts[((v * BINS as f64) as usize).min(BINS - 1)] += 1;
+        }
+        let visited = counts.iter().filter(|&&n| n > 0 && n * 100 >= samples).count();
+        visited as f64 / BINS as f64
+    }
+    /// Draw the curve between `t0` and `t1` as a line of `width` block characters,
+    /// from `▁` for values near 0 to `█` for values near 1.
+    ///
+    /// The curve is sampled at `width` evenly spaced points, starting at `t0` and
+    /// ending just before `t1`. Values outside `[0, 1]` are clamped.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let s = UnitSinusoid::new(1.0, 0.0);
+    /// let c = Meander1D::new(s, s, s);
+    /// let line = c.sparkline(0.0, 1.0, 64);
+    /// assert_eq!(line.chars().count(), 64);
+    /// for block in "▁▂▃▄▅▆▇█".chars() {
+    ///     assert!(line.contains(block));
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sparkline(&self, t0: f64, t1: f64, width: usize) -> String {
+        const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        let dt = (t1 - t0) / width as f64;
+        (0..width)
+            .map(|i| {
+                let v = self.evaluate(t0 + i as f64 * dt).clamp(0.0, 1.0);
+                BLOCKS[((v * 8.0) as usize).min(7)]
+            })
+            .collect()
+    }
+    /// Draw the curve between `t0` and `t1` as the `d` attribute of an SVG
+    /// `<path>` element, on a canvas `width` wide and `height` tall.
+    ///
+    /// The curve is sampled at `samples` evenly spaced points, from `t0` to `t1`
+    /// inclusive, which are joined by straight lines. Time runs from `x = 0` at `t0`
+    /// to `x = width` at `t1`, and values run from `y = height` at 0 up to `y = 0` at
+    /// 1 (since SVG's y axis points down). The result looks like
+    /// `M x0 y0 L x1 y1 L x2 y2 ...`, and is empty if `samples` is 0.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let s = UnitSinusoid::new(1.0, 0.0);
+    /// let c = Meander1D::new(s, s, s);
+    /// let path = c.to_svg_path(0.0, 1.0, 3, 200.0, 100.0);
+    /// assert_eq!(path, "M 0 100 L 100 0 L 200 100");
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let path = c.to_svg_path(0.0, 10.0, 50, 400.0, 100.0);
+    /// assert!(path.starts_with('M'));
+    /// let numbers: Vec<f64> = path.split(' ')
+    ///     .filter(|s| *s != "M" && *s != "L")
+    ///     .map(|s| s.parse().unwrap())
+    ///     .collect();
+    /// assert_eq!(numbers.len(), 2 * 50);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_svg_path(&self, t0: f64, t1: f64, samples: usize, width: f64, height: f64) -> String {
+        use core::fmt::Write;
+        let mut out = String::new();
+        let last = samples.saturating_sub(1).max(1) as f64;
+        for i in 0..samples {
+            let x = i as f64 / last;
+            let v = self.evaluate(t0 + x * (t1 - t0));
+            let command = if i == 0 { "M" } else { " L" };
+            let _ = write!(out, "{} {} {}", command, x * width, (1.0 - v) * height);
+        }
+        out
+    }
+    /// Precompute the values of the curve at `resolution` evenly spaced points in
+    /// `[0, period)`, for sampling with linear interpolation using `Lut::sample`.
+    ///
+    /// The table wraps around at `period`, so this should be a period of the curve,
+    /// such as the one returned by `Meander1D::period`; otherwise the sampled
+    /// curve jumps wherever it wraps. See `Lut` for how `resolution` affects the accuracy.
+    /// Panics if `resolution` is 0.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c = Meander1D::new(
+    ///     UnitSinusoid::new(1.0, 0.1),
+    ///     UnitSinusoid::new(2.0, 0.7),
+    ///     UnitSinusoid::new(3.0, 0.2),
+    /// );
+    /// let period = c.period().unwrap();
+    /// let lut = c.build_lut(period, 10_000);
+    /// for i in 0..=1000 {
+    ///     let t = i as f64 * period / 1000.0;
+    ///     assert!((lut.sample(t) - c.evaluate(t)).abs() < 1e-6);
+    ///     assert!((lut.sample(t - 5.0 * period) - c.evaluate(t)).abs() < 1e-6);
+    /// }
+    ///
+    /// let coarse = c.build_lut(period, 16);
+    /// assert!((coarse.sample(0.3) - c.evaluate(0.3)).abs() > 1e-6);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn build_lut(&self, period: f64, resolution: usize) -> Lut {
+        assert!(resolution > 0, "resolution must be positive");
+        let values = (0..resolution)
+            .map(|i| self.evaluate(i as f64 * period / resolution as f64))
+            .collect();
+        Lut { period, values }
+    }
+    /// Find the first time in `[t_start, t_end]` at which the curve crosses
+    /// `target`, or `None` if it doesn't.
+    ///
+    /// The interval is scanned in steps of 1/16 of a cycle of the fastest
+    /// component, looking for a step over which the curve goes from one side of
+    /// `target` to the other. That step is then narrowed down by bisection until it
+    /// is at most `1e-10` long. So a crossing is reported to within `1e-10`, but
+    /// a place where the curve only briefly touches `target` between two steps can
+    /// be missed.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// // Every component is the same, so this is just `(1 - cos(2πt)) / 2`.
+    /// let s = UnitSinusoid::new(1.0, 0.0);
+    /// let c = Meander1D::new(s, s, s);
+    /// let first = (-0.6f64).acos() / (2.0 * std::f64::consts::PI);
+    /// assert!((c.find_time(0.8, 0.0, 1.0).unwrap() - first).abs() < 1e-9);
+    /// assert!((c.find_time(0.8, 0.5, 1.0).unwrap() - (1.0 - first)).abs() < 1e-9);
+    /// assert!((c.find_time(0.5, 3.0, 4.0).unwrap() - 3.25).abs() < 1e-9);
+    /// assert_eq!(c.find_time(0.8, 0.0, 0.2), None);
+    /// assert_eq!(c.find_time(1.5, 0.0, 10.0), None);
+    /// ```
+    pub fn find_time(&self, target: f64, t_start: f64, t_end: f64) -> Option<f64> {
+        const TOLERANCE: f64 = 1e-10;
+        let fastest = self.components.iter().map(|s| s.frequency.abs()).fold(0.0, f64::max);
+        let span = t_end - t_start;
+        if span.is_nan() || span < 0.0 {
+            return None;
+        }
+        let steps = math::floor(span * fastest * 16.0).max(0.0) as usize + 1;
+        let dt = span / steps as f64;
+        let mut a = t_start;
+        let mut fa = self.evaluate(a) - target;
+        for i in 1..=steps {
+            if fa == 0.0 {
+                return Some(a);
+            }
+            let b = if i == steps { t_end } else { t_start + i as f64 * dt };
+            let fb = self.evaluate(b) - target;
+            if fa.signum() != fb.signum() || fb == 0.0 {
+                let (mut lo, mut hi) = (a, b);
+                while hi - lo > TOLERANCE {
+                    let mid = (lo + hi) / 2.0;
+                    if mid <= lo || mid >= hi {
+                        break;
+                    }
+                    let fm = self.evaluate(mid) - target;
+                    if fm == 0.0 {
+                        return Some(mid);
+                    }
+                    if fm.signum() == fa.signum() {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                return Some((lo + hi) / 2.0);
+            }
+            a = b;
+            fa = fb;
+        }
+        None
+    }
+    /// Turn this curve into a function from a point in time to its value at that
+    /// time, for passing to code that expects a closure.
+    ///
+    /// The function is `Send` and `Sync` whenever the curve is.
+    ///
+    /// ```
+    /// use meander::Meander1D;
+    ///
+    /// fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let f = c.clone().into_fn();
+    /// assert_send_sync(&f);
+    /// for &t in &[0.0, 0.3, 1.7, 42.0] {
+    ///     assert_eq!(f(t), c.evaluate(t));
+    /// }
+    /// ```
+    pub fn into_fn(self) -> impl Fn(f64) -> f64 {
+        move |t| self.evaluate(t)
+    }
+}
+
+impl<N, W> Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: PartialEq,
+{
+    /// Check whether each component of two curves, and their envelopes, are
+    /// approximately equal, in the sense of `UnitSinusoid::approx_eq`.
+    ///
+    /// ```
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let mut close = c.clone();
+    /// close.components[0].phase += 1e-12;
+    /// assert!(c.approx_eq(&close, 1e-9));
+    ///
+    /// let mut faded = c.clone();
+    /// faded.envelope = UnitSinusoid::constant(0.5);
+    /// assert!(!c.approx_eq(&faded, 1e-9));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.components.iter()
+            .zip(other.components.iter())
+            .all(|(a, b)| a.approx_eq(b, epsilon))
+            && self.envelope.approx_eq(&other.envelope, epsilon)
+    }
+}
+
+impl<N: ArrayLength<UnitSinusoid>> Meander1D<N> {
+    /// Find the rate of change of the curve at a given point in time.
+    pub fn derivative(&self, t: f64) -> f64 {
+        let slope = self.weighted_mean(self.components.iter().map(|s| s.derivative(t)));
+        let e = self.envelope;
+        if e.amplitude == 0.0 {
+            return slope * e.offset;
+        }
+        let mean = self.weighted_mean(self.components.iter().map(|s| s.evaluate(t)));
+        slope * e.evaluate(t) + mean * e.derivative(t)
+    }
+    /// Find both the value and the rate of change of the curve at a given point in
+    /// time, as given by `UnitSinusoid::evaluate_with_derivative`.
+    pub fn evaluate_with_derivative(&self, t: f64) -> (f64, f64) {
+        let mut value = 0.0;
+        let mut slope = 0.0;
+        for s in self.components.iter() {
+            let (v, d) = s.evaluate_with_derivative(t);
+            value += s.weight * v;
+            slope += s.weight * d;
+        }
+        let total = self.total_weight();
+        let (value, slope) = (value / total, slope / total);
+        let e = self.envelope;
+        if e.amplitude == 0.0 {
+            return (value * e.offset, slope * e.offset);
+        }
+        let (ev, ed) = e.evaluate_with_derivative(t);
+        (value * ev, slope * ev + value * ed)
+    }
+    /// Find the average value of the curve between `t0` and `t1`.
+    ///
+    /// This is computed analytically, so it stays accurate even over large windows.
+    ///
+    /// ```
+    /// use meander::Meander1D;
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let (t0, t1) = (1.0, 4.0);
+    /// let n = 100_000;
+    /// let dt = (t1 - t0) / n as f64;
+    /// let numerical: f64 = (0..n)
+    ///     .map(|i| c.evaluate(t0 + (i as f64 + 0.5) * dt))
+    ///     .sum::<f64>() / n as f64;
+    /// assert!((c.average_over(t0, t1) - numerical).abs() < 1e-5);
+    /// ```
+    pub fn average_over(&self, t0: f64, t1: f64) -> f64 {
+        let e = self.envelope;
+        if e.amplitude == 0.0 {
+            return self.weighted_mean(self.components.iter().map(|s| s.average_over(t0, t1))) * e.offset;
+        }
+        if t0 == t1 {
+            return self.evaluate(t0);
+        }
+        // The product of each component with the envelope expands into a constant
+        // and some cosines, each of which can be averaged separately.
+        let mean_cos = |omega: f64, theta: f64| {
+            if omega == 0.0 {
+                math::cos(theta)
+            } else {
+                (math::sin(omega * t1 + theta) - math::sin(omega * t0 + theta)) / (omega * (t1 - t0))
+            }
+        };
+        let (oe, te) = (PI2 * e.frequency, PI2 * e.frequency * e.phase);
+        let (c, d) = (e.offset + e.amplitude / 2.0, -e.amplitude / 2.0);
+        self.weighted_mean(self.components.iter().map(|s| {
+            let (o, th) = (PI2 * s.frequency, PI2 * s.frequency * s.phase);
+            let (a, b) = (s.offset + s.amplitude / 2.0, -s.amplitude / 2.0);
+            a * c + a * d * mean_cos(oe, te) + b * c * mean_cos(o, th)
+                + b * d / 2.0 * (mean_cos(o - oe, th - te) + mean_cos(o + oe, th + te))
+        }))
+    }
+    /// Find the long-run average value of the curve.
+    ///
+    /// This is the weighted average of the means of the components, as given by
+    /// `UnitSinusoid::mean`, so it is 0.5 unless their amplitudes or offsets have
+    /// been changed. It is multiplied by the mean of the envelope, which is exact as
+    /// long as the envelope's frequency is different from those of the components.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// assert!((c.mean() - 0.5).abs() < 1e-12);
+    ///
+    /// let low = UnitSinusoid { amplitude: 0.2, ..UnitSinusoid::new(1.3, 0.0) };
+    /// let high = UnitSinusoid { amplitude: 0.2, offset: 0.8, ..UnitSinusoid::new(2.9, 0.1) };
+    /// let c = Meander1D::<U2>::weighted([low, high].into(), [3.0, 1.0].into());
+    /// assert!((c.mean() - (3.0 * 0.1 + 0.9) / 4.0).abs() < 1e-12);
+    ///
+    /// let n = 100_000;
+    /// let numerical: f64 = (0..n).map(|i| c.evaluate(i as f64 * 0.01)).sum::<f64>() / n as f64;
+    /// assert!((numerical - c.mean()).abs() < 1e-3);
+    /// ```
+    pub fn mean(&self) -> f64 {
+        self.weighted_mean(self.components.iter().map(|s| s.mean())) * self.envelope.mean()
+    }
+    /// Express the curve as a constant plus a sum of cosines.
+    ///
+    /// This returns the constant along with one `(amplitude, frequency, phase)` term
+    /// per component, such that the value of the curve at `t` is the constant plus
+    /// the sum of `amplitude * cos(2π * frequency * (t + phase))` over the terms.
+    /// Since each haversine starts a cycle at its minimum, the amplitude of each
+    /// term has the opposite sign to that of its component.
+    ///
+    /// If the envelope isn't constant, multiplying it out produces a term for the
+    /// envelope itself and two more terms per component, at the sum and difference
+    /// of its frequency and the envelope's. (A difference of 0 is folded into the
+    /// constant.)
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::Meander1D;
+    ///
+    /// let c: Meander1D = meander::rand::random();
+    /// let (dc, terms) = c.as_cosine_sum();
+    /// assert_eq!(terms.len(), 3);
+    /// for &t in &[0.0, 0.3, 1.7, 12.5, -4.0] {
+    ///     let value: f64 = dc + terms.iter()
+    ///         .map(|&(a, f, p)| a * (2.0 * std::f64::consts::PI * f * (t + p)).cos())
+    ///         .sum::<f64>();
+    ///     assert!((value - c.evaluate(t)).abs() < 1e-9);
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn as_cosine_sum(&self) -> (f64, Vec<(f64, f64, f64)>) {
+        let dc = self.weighted_mean(self.components.iter().map(|s| s.offset + s.amplitude / 2.0));
+        let total = self.total_weight();
+        let e = self.envelope;
+        if e.amplitude == 0.0 {
+            let terms = self.components.iter()
+                .map(|s| (-s.weight * s.amplitude / (2.0 * total) * e.offset, s.frequency, s.phase))
+                .collect();
+            return (dc * e.offset, terms);
+        }
+        let terms: Vec<_> = self.components.iter()
+            .map(|s| (-s.weight * s.amplitude / (2.0 * total), s.frequency, s.phase))
+            .collect();
+        let (c, d) = (e.offset + e.amplitude / 2.0, -e.amplitude / 2.0);
+        let mut constant = dc * c;
+        let mut product = Vec::with_capacity(2 * terms.len() + 1);
+        product.push((dc * d, e.frequency, e.phase));
+        for &(a, f, p) in terms.iter() {
+            product.push((a * c, f, p));
+            // cos(x) cos(y) = (cos(x + y) + cos(x - y)) / 2
+            let (x, y) = (f * p, e.frequency * e.phase);
+            let sum = f + e.frequency;
+            product.push((a * d / 2.0, sum, (x + y) / sum));
+            let difference = f - e.frequency;
+            if difference == 0.0 {
+                constant += a * d / 2.0 * math::cos(PI2 * (x - y));
+            } else {
+                product.push((a * d / 2.0, difference, (x - y) / difference));
+            }
+        }
+        (constant, product)
+    }
+    /// Find the times between `t0` and `t1` at which the curve reaches a local
+    /// maximum or minimum.
+    ///
+    /// The interval is scanned in steps of `resolution` for changes in the sign of
+    /// the derivative, so turning points closer together than that may be missed.
+    /// Each one that is found is then refined by bisection to within
+    /// `resolution * 1e-9`, or as close as `f64` can represent if that is coarser.
+    /// Panics if `resolution` is not positive.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U1;
+    /// use meander::{Meander1D, UnitSinusoid};
+    ///
+    /// let c = Meander1D::<U1> { components: [UnitSinusoid::new(1.0, 0.0)].into(), ..Default::default() };
+    /// let extrema = c.extrema_in(0.1, 2.1, 0.01);
+    /// assert_eq!(extrema.len(), 4);
+    /// for (t, expected) in extrema.into_iter().zip(vec![0.5, 1.0, 1.5, 2.0]) {
+    ///     assert!((t - expected).abs() < 1e-9);
+    /// }
+    ///
+    /// let late = c.extrema_in(1e6, 1e6 + 1.0, 0.01);
+    /// assert_eq!(late.len(), 2);
+    /// assert!(late.iter().any(|&t| (t - (1e6 + 0.5)).abs() < 1e-6));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn extrema_in(&self, t0: f64, t1: f64, resolution: f64) -> Vec<f64> {
+        assert!(resolution > 0.0, "resolution must be positive");
+        let tolerance = resolution * 1e-9;
+        let mut extrema = Vec::new();
+        let mut a = t0;
+        let mut da = self.derivative(a);
+        let mut i = 1;
+        while a < t1 {
+            let b = (t0 + i as f64 * resolution).min(t1);
+            let db = self.derivative(b);
+            if da == 0.0 {
+                extrema.push(a);
+            } else if db != 0.0 && (da < 0.0) != (db < 0.0) {
+                let (mut lo, mut hi, mut dlo) = (a, b, da);
+                while hi - lo > tolerance {
+                    let mid = (lo + hi) / 2.0;
+                    if mid <= lo || mid >= hi {
+                        break;
+                    }
+                    let dmid = self.derivative(mid);
+                    if dmid == 0.0 {
+                        lo = mid;
+                        hi = mid;
+                    } else if (dmid < 0.0) == (dlo < 0.0) {
+                        lo = mid;
+                        dlo = dmid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                extrema.push((lo + hi) / 2.0);
+            }
+            a = b;
+            da = db;
+            i += 1;
+        }
+        if da == 0.0 {
+            extrema.push(a);
+        }
+        extrema
+    }
+}
+
+/// A curve whose value is the average of the values of several `Meander1D`s.
+///
+/// This can be used to layer several curves on the same variable, for example a
+/// slow meander and a fast jitter. Adding two `Meander1D`s produces a
+/// `CompositeCurve`, and adding a `Meander1D` to a `CompositeCurve` includes it in
+/// the average alongside the others.
+///
+/// This requires the `alloc` feature.
+///
+/// ```
+/// use meander::Meander1D;
+///
+/// let a: Meander1D = meander::rand::random();
+/// let b: Meander1D = meander::rand::random();
+/// let c: Meander1D = meander::rand::random();
+/// let composite = a.clone() + b.clone() + c.clone();
+/// for &t in &[0.0, 0.3, 2.0, 7.5] {
+///     let mean = (a.evaluate(t) + b.evaluate(t) + c.evaluate(t)) / 3.0;
+///     assert!((composite.evaluate(t) - mean).abs() < 1e-12);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CompositeCurve<N = U3, W = Haversin>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// The curves whose values are averaged.
+    pub curves: Vec<Meander1D<N, W>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<N, W> CompositeCurve<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Find the value of the curve at a given point in time.
+    pub fn evaluate(&self, t: f64) -> f64 {
+        let sum: f64 = self.curves.iter().map(|c| c.evaluate(t)).sum();
+        sum / self.curves.len() as f64
     }
 }
 
-impl Distribution<Meander1D> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander1D {
-        Meander1D(rng.gen(), rng.gen(), rng.gen())
+#[cfg(feature = "alloc")]
+impl<N, W> core::ops::Add for Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    type Output = CompositeCurve<N, W>;
+    fn add(self, other: Self) -> CompositeCurve<N, W> {
+        CompositeCurve { curves: alloc::vec![self, other] }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<N, W> core::ops::Add<Meander1D<N, W>> for CompositeCurve<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    type Output = CompositeCurve<N, W>;
+    fn add(mut self, other: Meander1D<N, W>) -> CompositeCurve<N, W> {
+        self.curves.push(other);
+        self
+    }
+}
+
+impl<N, W> Default for Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    /// A curve whose components are all `UnitSinusoid::default()`.
+    fn default() -> Self {
+        Meander1D { components: GenericArray::default(), envelope: default_envelope() }
+    }
+}
+
+impl<N, W> Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    /// Create a curve that is always exactly `value`.
+    ///
+    /// Every component has an amplitude of 0 and an offset of `value`, and only
+    /// the first has a nonzero weight, so that averaging them doesn't introduce
+    /// any rounding error.
+    ///
+    /// ```
+    /// use meander::Meander1D;
+    ///
+    /// let c: Meander1D = Meander1D::constant(0.7);
+    /// for &t in &[0.0, 0.35, 12.0, -4.5] {
+    ///     assert_eq!(c.evaluate(t), 0.7);
+    /// }
+    /// ```
+    pub fn constant(value: f64) -> Self {
+        let mut curve = Self::default();
+        for (i, s) in curve.components.iter_mut().enumerate() {
+            s.amplitude = 0.0;
+            s.offset = value;
+            s.weight = if i == 0 { 1.0 } else { 0.0 };
+        }
+        curve
+    }
+}
+
+impl<N, W> Distribution<Meander1D<N, W>> for Standard
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander1D<N, W> {
+        Meander1D {
+            components: <GenericArray<_, _> as GenericSequence<_>>::generate(|_| rng.gen()),
+            envelope: default_envelope(),
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, W: Default> arbitrary::Arbitrary<'a> for UnitSinusoid<W> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Map each `u32` into `[0, 1)`, then into the ranges used by `UnitSinusoidDist`.
+        let mut unit = || -> arbitrary::Result<f64> {
+            Ok(f64::from(u32::arbitrary(u)?) / (f64::from(u32::MAX) + 1.0))
+        };
+        let dist = UnitSinusoidDist::default();
+        let frequency = dist.freq_min + unit()? * (dist.freq_max - dist.freq_min);
+        let phase = unit()? / frequency;
+        Ok(UnitSinusoid { frequency, phase, ..Default::default() })
+    }
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (8, Some(8))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, N, W> arbitrary::Arbitrary<'a> for Meander1D<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut curve = Meander1D::default();
+        for s in curve.components.iter_mut() {
+            *s = u.arbitrary()?;
+        }
+        Ok(curve)
+    }
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (lo, hi) = UnitSinusoid::<W>::size_hint(depth);
+        (lo * N::USIZE, hi.map(|hi| hi * N::USIZE))
     }
 }
 
 /// Represents a curve that meanders through `D`-dimensional space.
 ///
-/// This can be generated randomly using `rand::random()`.
-#[derive(Clone, Debug)]
-pub struct Meander<D: ArrayLength<Meander1D>> {
+/// Each variable's curve is made of `N` sinusoids with waveform `W`, 3 `Haversin`s
+/// by default.
+///
+/// This can be generated randomly using `rand::random()`, or a fixed reference
+/// meander can be created with `Meander::default()`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(serialize = "", deserialize = "W: Default")),
+)]
+pub struct Meander<D, N = U3, W = Haversin>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
     /// Each variable is controlled by a separate 1-dimensional function defined here.
-    pub curves: GenericArray<Meander1D, D>,
+    pub curves: GenericArray<Meander1D<N, W>, D>,
+}
+
+impl<D, N, W> TryFrom<&[Meander1D<N, W>]> for Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Clone,
+{
+    type Error = LengthError;
+    /// Create a meander from a slice of curves, which must have exactly `D`
+    /// elements.
+    ///
+    /// ```
+    /// use core::convert::TryFrom;
+    /// use meander::typenum::U2;
+    /// use meander::{LengthError, Meander, Meander1D};
+    ///
+    /// let curves: Vec<Meander1D> = (0..2).map(|_| meander::rand::random()).collect();
+    /// let m = Meander::<U2>::try_from(&curves[..]).unwrap();
+    /// assert_eq!(m.curves.as_slice(), curves.as_slice());
+    ///
+    /// let err = Meander::<U2>::try_from(&curves[..1]).unwrap_err();
+    /// assert_eq!(err, LengthError { expected: 2, found: 1 });
+    /// assert_eq!(err.to_string(), "expected 2 curves, found 1");
+    /// ```
+    fn try_from(curves: &[Meander1D<N, W>]) -> Result<Self, LengthError> {
+        GenericArray::from_exact_iter(curves.iter().cloned())
+            .map(|curves| Meander { curves })
+            .ok_or(LengthError { expected: D::to_usize(), found: curves.len() })
+    }
+}
+
+impl<const K: usize, D, N, W> From<[Meander1D<N, W>; K]> for Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    GenericArray<Meander1D<N, W>, D>: From<[Meander1D<N, W>; K]>,
+{
+    /// Create a meander from an array of curves, which must have exactly `D`
+    /// elements.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::{Meander, Meander1D};
+    ///
+    /// let a: Meander1D = meander::rand::random();
+    /// let b: Meander1D = meander::rand::random();
+    /// let m: Meander<U2> = [a.clone(), b].into();
+    /// assert_eq!(m.curves[0], a);
+    /// ```
+    fn from(curves: [Meander1D<N, W>; K]) -> Self {
+        Meander { curves: curves.into() }
+    }
 }
 
-impl<D: ArrayLength<Meander1D>> Distribution<Meander<D>> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander<D> {
+/// The error returned when creating a meander from a slice with the wrong number
+/// of curves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthError {
+    /// The number of curves that were needed.
+    pub expected: usize,
+    /// The number of curves that were given.
+    pub found: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {} curves, found {}", self.expected, self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthError {}
+
+impl<D, N, W> Default for Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    /// A meander whose curves are all `Meander1D::default()`.
+    ///
+    /// Every component is then a sinusoid with frequency 1, phase 0, amplitude 1 and
+    /// offset 0, so this is a fixed reference meander that doesn't depend on an RNG.
+    /// With the default `Haversin` waveform, every variable is 0 at whole-number
+    /// times and 1 halfway between them.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m = Meander::<U3>::default();
+    /// assert_eq!(m.evaluate(0.0).as_slice(), &[0.0, 0.0, 0.0]);
+    /// assert_eq!(m.evaluate(0.5).as_slice(), &[1.0, 1.0, 1.0]);
+    /// ```
+    fn default() -> Self {
+        Meander { curves: GenericArray::default() }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, D, N, W> arbitrary::Arbitrary<'a> for Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    /// ```
+    /// # #[cfg(feature = "arbitrary")] {
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use meander::rand::Rng;
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let mut bytes = vec![0u8; 256];
+    /// for i in 0..1000 {
+    ///     match i {
+    ///         0 => bytes.iter_mut().for_each(|b| *b = 0),
+    ///         1 => bytes.iter_mut().for_each(|b| *b = 0xff),
+    ///         _ => rng.fill(&mut bytes[..]),
+    ///     }
+    ///     let m = Meander::<U3>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    ///     for &t in &[0.0, 1.5, 1e6] {
+    ///         assert!(m.evaluate(t).iter().all(|v| v.is_finite()));
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut meander = Meander::default();
+        for c in meander.curves.iter_mut() {
+            *c = u.arbitrary()?;
+        }
+        Ok(meander)
+    }
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        let (lo, hi) = Meander1D::<N, W>::size_hint(depth);
+        (lo * D::USIZE, hi.map(|hi| hi * D::USIZE))
+    }
+}
+
+impl<D, N, W> Distribution<Meander<D, N, W>> for Standard
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander<D, N, W> {
         Meander {
             curves: <GenericArray<_, _> as GenericSequence<_>>::generate(|_| rng.gen()),
         }
     }
 }
 
-impl<D: ArrayLength<Meander1D> + ArrayLength<f64>> Meander<D> {
-    /// Find the value of each of the variables at a particular point in time.
-    pub fn evaluate(&self, t: f64) -> GenericArray<f64, D> {
-        (&self).curves.clone().map(|c| c.evaluate(t))
+/// A distribution over curves whose component frequencies are spread out evenly,
+/// rather than being sampled independently.
+///
+/// Independent sampling sometimes puts all of a curve's frequencies close together,
+/// which makes its motion look like a single sinusoid. Instead, this picks a random
+/// starting point `x` in `[0, 1)` for each curve and gives its `k`th component the
+/// frequency at `fract(x + k / φ)` of the way from `freq_min` to `freq_max`, where
+/// `φ` is the golden ratio. (This is the additive recurrence with the golden ratio,
+/// a low-discrepancy sequence.) By the three-gap theorem, the frequencies of a
+/// three-component curve are then always at least `0.236 * (freq_max - freq_min)`
+/// apart.
+///
+/// Phases are sampled the same way as for `UnitSinusoidDist`. The default range is
+/// `1.0..10.0`. `freq_min` must be positive.
+///
+/// ```
+/// use meander::rand::{self, Rng};
+/// use meander::{Meander1D, QuasiDist};
+///
+/// let mut rng = rand::thread_rng();
+/// for _ in 0..1000 {
+///     let c: Meander1D = rng.sample(QuasiDist::default());
+///     let f: Vec<f64> = c.components.iter().map(|s| s.frequency).collect();
+///     assert!((f[0] - f[1]).abs() > 2.0);
+///     assert!((f[1] - f[2]).abs() > 2.0);
+///     assert!((f[0] - f[2]).abs() > 2.0);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct QuasiDist {
+    /// The smallest frequency that can be generated.
+    pub freq_min: f64,
+    /// The upper bound (exclusive) of the frequencies that can be generated.
+    pub freq_max: f64,
+}
+
+impl Default for QuasiDist {
+    fn default() -> Self {
+        QuasiDist { freq_min: 1.0, freq_max: 10.0 }
     }
-    /// Return an iterator yielding the values of the variables at intervals of `dt`.
-    pub fn time_steps<'a>(&'a self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> + 'a {
-        (0..).map(move |i| self.evaluate(i as f64 * dt))
+}
+
+/// The reciprocal of the golden ratio.
+const INV_PHI: f64 = 0.618_033_988_749_894_9;
+
+impl<N, W> Distribution<Meander1D<N, W>> for QuasiDist
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander1D<N, W> {
+        let start: f64 = rng.gen();
+        Meander1D {
+            components: <GenericArray<_, _> as GenericSequence<_>>::generate(|k| {
+                let x = start + k as f64 * INV_PHI;
+                let x = x - math::floor(x);
+                let frequency = self.freq_min + x * (self.freq_max - self.freq_min);
+                let phase = rng.gen_range(0.0, frequency.recip());
+                UnitSinusoid { frequency, phase, ..Default::default() }
+            }),
+            envelope: default_envelope(),
+        }
     }
-    /// Return an iterator yielding the values of the variables at intervals of `dt`.
-    /// Consumes `self`.
-    pub fn into_time_steps(self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> {
-        (0..).map(move |i| self.evaluate(i as f64 * dt))
+}
+
+impl<D, N, W> Distribution<Meander<D, N, W>> for QuasiDist
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Meander<D, N, W> {
+        Meander {
+            curves: <GenericArray<_, _> as GenericSequence<_>>::generate(|_| self.sample(rng)),
+        }
+    }
+}
+
+/// A distribution that samples meanders from another distribution and wraps them
+/// in a `HighContrastMeander`, whose values reach the extremes of `[0, 1]` more
+/// often.
+///
+/// The variables of an ordinary random meander rarely get near 0 or 1 (see
+/// `Meander::random_tour`, which instead adjusts the sinusoids themselves). Here
+/// the meander is left as it is, and each value `v` it produces is passed through
+/// the S-shaped curve
+///
+/// ```text
+/// v^contrast / (v^contrast + (1 - v)^contrast)
+/// ```
+///
+/// when it is evaluated. This maps `[0, 1]` onto itself, keeps 0, 0.5 and 1 where
+/// they are, and never changes the order of two values, so the output stays in
+/// `[0, 1]` and rises and falls at the same times as the plain meander. With a
+/// `contrast` above 1, values are pushed away from 0.5 towards whichever end they
+/// are closer to; a contrast of 1 leaves them unchanged, and one between 0 and 1
+/// pulls them towards 0.5 instead. Values outside `[0, 1]` are clamped first.
+///
+/// By default, meanders are sampled as if by `rand::random()` and the contrast is
+/// 2.
+///
+/// ```
+/// use meander::rand::{Rng, SeedableRng};
+/// use meander::rand::rngs::StdRng;
+/// use meander::typenum::U3;
+/// use meander::{HighContrastDist, HighContrastMeander, Meander};
+///
+/// let plain: Meander<U3> = StdRng::seed_from_u64(5).gen();
+/// let high: HighContrastMeander<U3> = StdRng::seed_from_u64(5).sample(HighContrastDist::default());
+/// assert_eq!(high.inner(), &plain);
+///
+/// let range = |values: Vec<f64>| {
+///     let (lo, hi) = values.iter().fold((1.0f64, 0.0f64), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+///     assert!(0.0 <= lo && hi <= 1.0);
+///     hi - lo
+/// };
+/// for i in 0..3 {
+///     let p = range(plain.time_steps(0.01).take(10_000).map(|v| v[i]).collect());
+///     let h = range(high.time_steps(0.01).take(10_000).map(|v| v[i]).collect());
+///     assert!(h > p);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HighContrastDist<T = Standard> {
+    inner: T,
+    contrast: f64,
+}
+
+impl<T> HighContrastDist<T> {
+    /// Create a distribution that samples meanders from `inner` and applies the
+    /// given contrast to them.
+    ///
+    /// Panics if `contrast` is not positive and finite.
+    ///
+    /// ```should_panic
+    /// meander::HighContrastDist::new(meander::rand::distributions::Standard, -1.0);
+    /// ```
+    pub fn new(inner: T, contrast: f64) -> Self {
+        assert!(contrast > 0.0 && contrast.is_finite(), "contrast must be positive and finite");
+        HighContrastDist { inner, contrast }
+    }
+    /// Return the contrast applied to the sampled meanders.
+    pub fn contrast(&self) -> f64 {
+        self.contrast
+    }
+}
+
+impl Default for HighContrastDist {
+    fn default() -> Self {
+        HighContrastDist::new(Standard, 2.0)
+    }
+}
+
+impl<T, D, N, W> Distribution<HighContrastMeander<D, N, W>> for HighContrastDist<T>
+where
+    T: Distribution<Meander<D, N, W>>,
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HighContrastMeander<D, N, W> {
+        HighContrastMeander { meander: self.inner.sample(rng), contrast: self.contrast }
+    }
+}
+
+/// A builder for generating random meanders with custom settings.
+///
+/// The frequency of each component is sampled uniformly from the frequency range,
+/// which is `1.0..10.0` by default, and the phase is sampled the same way as for
+/// `UnitSinusoidDist`. The number of components in each curve and the waveform of
+/// each component can also be changed; by default there are 3 `Haversin`s.
+///
+/// ```
+/// use meander::typenum::{U2, U5};
+/// use meander::{BuildError, MeanderBuilder, Triangle};
+///
+/// let mut rng = meander::rand::thread_rng();
+/// let m = MeanderBuilder::<U2>::new()
+///     .frequency_range(0.1, 0.5)
+///     .components::<U5>()
+///     .waveform(Triangle)
+///     .build(&mut rng)
+///     .unwrap();
+/// for curve in m.curves.iter() {
+///     assert_eq!(curve.components.len(), 5);
+///     for s in curve.components.iter() {
+///         assert!(0.1 <= s.frequency && s.frequency < 0.5);
+///         assert_eq!(s.waveform, Triangle);
+///     }
+/// }
+///
+/// let err = MeanderBuilder::<U2>::new().frequency_range(2.0, 1.0).build(&mut rng);
+/// assert_eq!(err, Err(BuildError::InvalidFrequencyRange { min: 2.0, max: 1.0 }));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MeanderBuilder<D, N = U3, W = Haversin> {
+    freq_min: f64,
+    freq_max: f64,
+    waveform: W,
+    dimensions: PhantomData<(D, N)>,
+}
+
+impl<D> MeanderBuilder<D> {
+    /// Create a builder with the default settings.
+    pub fn new() -> Self {
+        MeanderBuilder {
+            freq_min: 1.0,
+            freq_max: 10.0,
+            waveform: Haversin,
+            dimensions: PhantomData,
+        }
+    }
+}
+
+impl<D> Default for MeanderBuilder<D> {
+    fn default() -> Self {
+        MeanderBuilder::new()
+    }
+}
+
+impl<D, N, W> MeanderBuilder<D, N, W> {
+    /// Set the range that the frequencies of the components are sampled from.
+    /// `min` must be positive and less than `max`.
+    pub fn frequency_range(mut self, min: f64, max: f64) -> Self {
+        self.freq_min = min;
+        self.freq_max = max;
+        self
+    }
+    /// Set the number of components in each curve to `M`.
+    pub fn components<M>(self) -> MeanderBuilder<D, M, W> {
+        MeanderBuilder {
+            freq_min: self.freq_min,
+            freq_max: self.freq_max,
+            waveform: self.waveform,
+            dimensions: PhantomData,
+        }
+    }
+    /// Set the waveform of every component.
+    pub fn waveform<V: Waveform>(self, waveform: V) -> MeanderBuilder<D, N, V> {
+        MeanderBuilder {
+            freq_min: self.freq_min,
+            freq_max: self.freq_max,
+            waveform,
+            dimensions: PhantomData,
+        }
+    }
+}
+
+impl<D, N, W> MeanderBuilder<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Generate a random meander with these settings, or return an error if they
+    /// are invalid.
+    pub fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Meander<D, N, W>, BuildError> {
+        let (freq_min, freq_max) = (self.freq_min, self.freq_max);
+        if !(freq_min > 0.0 && freq_min < freq_max && freq_max.is_finite()) {
+            return Err(BuildError::InvalidFrequencyRange { min: freq_min, max: freq_max });
+        }
+        let mut component = || {
+            let frequency: f64 = rng.gen_range(freq_min, freq_max);
+            let phase = rng.gen_range(0.0, frequency.recip());
+            UnitSinusoid {
+                frequency,
+                phase,
+                amplitude: 1.0,
+                offset: 0.0,
+                weight: 1.0,
+                waveform: self.waveform,
+            }
+        };
+        let curves = GenericArray::generate(|_| Meander1D {
+            components: GenericArray::generate(|_| component()),
+            envelope: default_envelope(),
+        });
+        Ok(Meander { curves })
+    }
+}
+
+/// The error returned by `Meander::from_params` when the parameters are invalid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParamError {
+    /// One of the slices of parameters had the wrong number of elements.
+    WrongLength {
+        /// The number of elements that were needed.
+        expected: usize,
+        /// The number of elements that were given.
+        found: usize,
+    },
+    /// One of the frequencies was zero, negative or `NaN`.
+    NonPositiveFrequency {
+        /// The index of the frequency.
+        index: usize,
+        /// The frequency.
+        frequency: f64,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParamError::WrongLength { expected, found } => {
+                write!(f, "expected {} parameters, found {}", expected, found)
+            }
+            ParamError::NonPositiveFrequency { index, frequency } => {
+                write!(f, "frequency {} at index {} is not positive", frequency, index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParamError {}
+
+/// The error returned when a `MeanderBuilder` has invalid settings.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BuildError {
+    /// The frequency range was empty, or its minimum wasn't positive.
+    InvalidFrequencyRange {
+        /// The smallest frequency that was requested.
+        min: f64,
+        /// The upper bound of the frequencies that was requested.
+        max: f64,
+    },
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            BuildError::InvalidFrequencyRange { min, max } => write!(
+                f,
+                "invalid frequency range {}..{}: the minimum must be positive and less than the maximum",
+                min, max,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+impl<D, N> Meander<D, N>
+where
+    D: ArrayLength<Meander1D<N>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid>,
+{
+    /// Find the rate of change of each of the variables at a particular point in time.
+    pub fn derivative(&self, t: f64) -> GenericArray<f64, D> {
+        self.curves.iter().map(|c| c.derivative(t)).collect()
+    }
+    /// Find both the value and the rate of change of each of the variables at a
+    /// particular point in time, as given by `Meander1D::evaluate_with_derivative`.
+    ///
+    /// Unlike calling `evaluate` and `derivative` separately, this computes the angle
+    /// of each component only once.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let plain: Meander<U3> = meander::rand::random();
+    /// let enveloped: Meander<U3> = Meander::random_with_envelope((0.01, 0.1), &mut rng);
+    /// for m in &[plain, enveloped] {
+    ///     for &t in &[0.0, 0.3, 1.7, 42.0] {
+    ///         let (values, slopes) = m.evaluate_with_derivative(t);
+    ///         let (expected_values, expected_slopes) = (m.evaluate(t), m.derivative(t));
+    ///         for i in 0..3 {
+    ///             assert!((values[i] - expected_values[i]).abs() < 1e-12);
+    ///             assert!((slopes[i] - expected_slopes[i]).abs() < 1e-9);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn evaluate_with_derivative(&self, t: f64) -> (GenericArray<f64, D>, GenericArray<f64, D>) {
+        let mut values = GenericArray::default();
+        let mut slopes = GenericArray::default();
+        for ((v, d), c) in values.iter_mut().zip(slopes.iter_mut()).zip(self.curves.iter()) {
+            let (value, slope) = c.evaluate_with_derivative(t);
+            *v = value;
+            *d = slope;
+        }
+        (values, slopes)
+    }
+    /// Find the average value of each of the variables between `t0` and `t1`.
+    pub fn average_over(&self, t0: f64, t1: f64) -> GenericArray<f64, D> {
+        self.curves.iter().map(|c| c.average_over(t0, t1)).collect()
+    }
+    /// Generate a random meander whose variables start out at the values in
+    /// `start`, so that `evaluate(0.0)` is `start`.
+    ///
+    /// The frequencies are sampled as usual. Rather than solving for the average of
+    /// the components, the phase of every component is chosen so that it starts at
+    /// the requested value on its own, in either its rising or falling half (picked
+    /// at random). Since the components all agree, so does their average. Values
+    /// outside `[0, 1]` can't be reached and are clamped to that range first. Up to
+    /// rounding error, which is much smaller than `1e-9`, the start is exact.
+    ///
+    /// ```
+    /// use meander::rand::SeedableRng;
+    /// use meander::rand::rngs::StdRng;
+    /// use meander::generic_array::arr;
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// for seed in 0..200 {
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     let start = arr![f64; 0.0, 0.35, 1.0];
+    ///     let m: Meander<U3> = Meander::random_starting_at(start, &mut rng);
+    ///     for (a, b) in m.evaluate(0.0).iter().zip(start.iter()) {
+    ///         assert!((a - b).abs() < 1e-9);
+    ///     }
+    /// }
+    /// ```
+    pub fn random_starting_at<R: Rng + ?Sized>(start: GenericArray<f64, D>, rng: &mut R) -> Self {
+        let mut m: Self = rng.gen();
+        for (c, &v) in m.curves.iter_mut().zip(start.iter()) {
+            let v = v.clamp(0.0, 1.0);
+            let rising = math::acos(1.0 - 2.0 * v) / PI2;
+            for s in c.components.iter_mut() {
+                let cycles = if rng.gen() { rising } else { 1.0 - rising };
+                s.phase = cycles / s.frequency;
+            }
+        }
+        m
+    }
+    /// Find how fast the meander is moving through space at a particular point in
+    /// time. This is the length (L2 norm) of `derivative(t)`.
+    ///
+    /// ```
+    /// use meander::generic_array::arr;
+    /// use meander::{Meander, Meander1D, UnitSinusoid};
+    ///
+    /// let slow = UnitSinusoid::new(0.1, 0.0);
+    /// let fast = UnitSinusoid::new(5.0, 0.05);
+    /// let m = Meander::from_curves(arr![Meander1D;
+    ///     Meander1D::new(slow, slow, slow),
+    ///     Meander1D::new(fast, fast, fast),
+    ///     Meander1D::new(slow, slow, slow),
+    /// ]);
+    /// assert_eq!(m.fastest_changing_dimension(0.0), 1);
+    /// let speed = 5.0 * std::f64::consts::PI;
+    /// assert!((m.gradient_magnitude(0.0) - speed).abs() < 1e-9);
+    /// ```
+    pub fn gradient_magnitude(&self, t: f64) -> f64 {
+        let sum: f64 = self.curves.iter().map(|c| c.derivative(t)).map(|d| d * d).sum();
+        math::sqrt(sum)
+    }
+    /// Find the index of the variable that is changing fastest at a particular
+    /// point in time, which is the one whose derivative has the largest absolute
+    /// value. If there is a tie, the first such variable is used.
+    pub fn fastest_changing_dimension(&self, t: f64) -> usize {
+        let mut best = (0, f64::NEG_INFINITY);
+        for (i, c) in self.curves.iter().enumerate() {
+            let d = c.derivative(t).abs();
+            if d > best.1 {
+                best = (i, d);
+            }
+        }
+        best.0
+    }
+}
+
+impl<D, N, W> Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Find the value of each of the variables at a particular point in time.
+    ///
+    /// `t` may be negative, which can be used to extend the meander backward in
+    /// time from `t = 0`. This doesn't clone the meander or allocate; use
+    /// `evaluate_into` to also reuse the output buffer.
+    pub fn evaluate(&self, t: f64) -> GenericArray<f64, D> {
+        let mut out = GenericArray::default();
+        self.evaluate_into(t, &mut out);
+        out
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// writing them into `out`.
+    ///
+    /// This lets the same buffer be reused across calls.
+    ///
+    /// ```
+    /// use meander::generic_array::GenericArray;
+    /// use meander::typenum::U12;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U12> = meander::rand::random();
+    /// let mut out = GenericArray::default();
+    /// for i in 0..60 {
+    ///     let t = i as f64 / 60.0;
+    ///     m.evaluate_into(t, &mut out);
+    ///     assert_eq!(out, m.evaluate(t));
+    /// }
+    /// ```
+    pub fn evaluate_into(&self, t: f64, out: &mut GenericArray<f64, D>) {
+        for (o, c) in out.iter_mut().zip(self.curves.iter()) {
+            *o = c.evaluate(t);
+        }
+    }
+    /// Draw each of the variables between `t0` and `t1` as a sparkline, as given by
+    /// `Meander1D::sparkline`.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let lines = m.sparklines(0.0, 10.0, 40);
+    /// assert_eq!(lines.len(), 3);
+    /// assert_eq!(lines[1], m.curves[1].sparkline(0.0, 10.0, 40));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sparklines(&self, t0: f64, t1: f64, width: usize) -> Vec<String> {
+        self.curves.iter().map(|c| c.sparkline(t0, t1, width)).collect()
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// returning them in a `Vec`.
+    ///
+    /// This is useful for passing the values across an FFI boundary, such as to
+    /// JavaScript as a `Float64Array`, without exposing `GenericArray`.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let flat = m.evaluate_flat(0.25);
+    /// assert_eq!(flat.len(), 3);
+    /// assert_eq!(flat.as_slice(), m.evaluate(0.25).as_slice());
+    ///
+    /// let mut out = [0.0; 4];
+    /// m.fill_flat(0.25, &mut out);
+    /// assert_eq!(&out[..3], flat.as_slice());
+    /// assert_eq!(out[3], 0.0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn evaluate_flat(&self, t: f64) -> Vec<f64> {
+        self.curves.iter().map(|c| c.evaluate(t)).collect()
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// writing them into the first `D` elements of `out`.
+    ///
+    /// Panics if `out` has fewer than `D` elements.
+    pub fn fill_flat(&self, t: f64, out: &mut [f64]) {
+        assert!(out.len() >= self.curves.len(), "output buffer is too short");
+        for (o, c) in out.iter_mut().zip(self.curves.iter()) {
+            *o = c.evaluate(t);
+        }
+    }
+    /// Sample the variables at `width` intervals of `dt`, starting at time 0, and
+    /// return them as a grid of `f32`s ready to be uploaded as a texture.
+    ///
+    /// The grid has one row of `width` values per variable, and the rows are laid
+    /// out one after another, so the result has `width * D` elements. The value of
+    /// variable `dim` at time `i as f64 * dt` is at index `dim * width + i`. Each
+    /// value is converted from `evaluate` with `as f32`.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let texture = m.to_texture_flat(0.1, 64);
+    /// assert_eq!(texture.len(), 64 * 3);
+    /// for dim in 0..3 {
+    ///     for i in 0..64 {
+    ///         assert_eq!(texture[dim * 64 + i], m.evaluate(i as f64 * 0.1)[dim] as f32);
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_texture_flat(&self, dt: f64, width: usize) -> Vec<f32> {
+        let mut out = Vec::with_capacity(width * self.curves.len());
+        for c in self.curves.iter() {
+            out.extend((0..width).map(|i| c.evaluate(i as f64 * dt) as f32));
+        }
+        out
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    ///
+    /// The time of step `i` is computed as `i as f64 * dt`, where `i` is a `u64`, so
+    /// this can be iterated for as long as is practical without overflowing. (Past
+    /// 2^53 steps, `i as f64` is no longer exact, so the times of
+    /// neighboring steps start to coincide.) This is also true of the other time
+    /// step iterators. Skipping ahead with `nth` takes constant time.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let i = 3_000_000_000usize;
+    /// assert_eq!(m.time_steps(0.01).nth(i), Some(m.evaluate(i as f64 * 0.01)));
+    /// assert_eq!(m.time_steps(0.01).nth(i), Some(m.evaluate(30_000_000.0)));
+    /// ```
+    pub fn time_steps<'a>(&'a self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> + 'a {
+        TimeSteps { meander: self, dt, step: 0 }
+    }
+    /// Return an iterator yielding the moving average of the values of the
+    /// variables over the last `window` intervals of `dt`. Consumes `self`.
+    ///
+    /// The last `window` samples are kept in a buffer. Until `window` samples have
+    /// been taken, the average is over all of the samples so far, so the first
+    /// item is the same as the first item of `into_time_steps(dt)` and there is
+    /// still one item per time step. Panics if `window` is 0.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let raw: Vec<_> = m.clone().into_time_steps(0.1).take(100).collect();
+    /// let same: Vec<_> = m.clone().smoothed_time_steps(0.1, 1).take(100).collect();
+    /// assert_eq!(raw, same);
+    ///
+    /// let smooth: Vec<_> = m.smoothed_time_steps(0.1, 4).take(100).collect();
+    /// let mean = (raw[6][0] + raw[7][0] + raw[8][0] + raw[9][0]) / 4.0;
+    /// assert!((smooth[9][0] - mean).abs() < 1e-12);
+    /// assert!((smooth[1][0] - (raw[0][0] + raw[1][0]) / 2.0).abs() < 1e-12);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn smoothed_time_steps(self, dt: f64, window: usize)
+        -> impl Iterator<Item=GenericArray<f64, D>>
+    {
+        assert!(window > 0, "window must be positive");
+        let mut buffer: Vec<GenericArray<f64, D>> = Vec::with_capacity(window);
+        self.into_time_steps(dt).enumerate().map(move |(i, v)| {
+            if buffer.len() < window {
+                buffer.push(v);
+            } else {
+                buffer[i % window] = v;
+            }
+            let mut sum: GenericArray<f64, D> = GenericArray::default();
+            for sample in buffer.iter() {
+                for (s, &x) in sum.iter_mut().zip(sample.iter()) {
+                    *s += x;
+                }
+            }
+            let n = buffer.len() as f64;
+            sum.map(|s| s / n)
+        })
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// where the time of each step is found by adding `dt` to the time of the
+    /// previous step.
+    ///
+    /// Unlike `time_steps`, the rounding error of every addition accumulates, so
+    /// the times drift away from `i as f64 * dt` over many steps. Once the time is
+    /// large enough that adding `dt` to it has no effect, it stops advancing
+    /// altogether. This is only useful for matching code that tracks time the same
+    /// way.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let steps: Vec<_> = m.time_steps_accumulated(0.1).take(4).collect();
+    /// assert_eq!(steps[3], m.evaluate(0.1 + 0.1 + 0.1));
+    /// ```
+    pub fn time_steps_accumulated<'a>(&'a self, dt: f64)
+        -> impl Iterator<Item=GenericArray<f64, D>> + 'a
+    {
+        core::iter::successors(Some(0.0), move |&t| Some(t + dt)).map(move |t| self.evaluate(t))
+    }
+    /// Return an iterator yielding the values of the variables at the first `n`
+    /// intervals of `dt`.
+    ///
+    /// Unlike `time_steps(dt).take(n)`, this can also be iterated in reverse.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let steps = m.time_steps_bounded(0.1, 50);
+    /// assert_eq!(steps.len(), 50);
+    /// assert_eq!(steps.rev().next(), Some(m.evaluate(49.0 * 0.1)));
+    /// ```
+    pub fn time_steps_bounded(&self, dt: f64, n: usize) -> BoundedTimeSteps<'_, D, N, W> {
+        BoundedTimeSteps { meander: self, dt, front: 0, back: n }
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    /// Consumes `self`.
+    pub fn into_time_steps(self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> {
+        self.iter(dt)
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// as a named type that can be stored and cloned. Consumes `self`.
+    pub fn iter(self, dt: f64) -> MeanderIter<D, N, W> {
+        MeanderIter { meander: self, dt, step: 0 }
+    }
+    /// Return a `MeanderStepper`, which yields the values of the variables at
+    /// intervals of `dt` by keeping track of how far through its cycle each
+    /// component is. Consumes `self`.
+    pub fn stepper(self, dt: f64) -> MeanderStepper<D, N, W>
+    where
+        D: ArrayLength<GenericArray<f64, N>>,
+        N: ArrayLength<f64>,
+    {
+        let fract = |x: f64| x - math::floor(x);
+        let fractions = self.curves.iter()
+            .map(|c| c.components.iter().map(|s| fract(s.frequency * s.phase)).collect())
+            .collect();
+        let deltas = self.curves.iter()
+            .map(|c| c.components.iter().map(|s| fract(s.frequency * dt)).collect())
+            .collect();
+        MeanderStepper { meander: self, fractions, deltas, dt, step: 0 }
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// starting at `t0`. Consumes `self`.
+    ///
+    /// `dt` may be negative, in which case the iterator walks backward in time.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let steps: Vec<_> = m.clone().into_time_steps_from(0.0, -0.01).take(100).collect();
+    /// for (i, v) in steps.iter().enumerate() {
+    ///     assert_eq!(*v, m.evaluate(-(i as f64) * 0.01));
+    /// }
+    /// assert!(steps.iter().flatten().all(|&v| 0.0 <= v && v <= 1.0));
+    /// ```
+    pub fn into_time_steps_from(self, t0: f64, dt: f64)
+        -> impl Iterator<Item=GenericArray<f64, D>>
+    {
+        (0u64..).map(move |i| self.evaluate(t0 + i as f64 * dt))
+    }
+    /// Return an iterator yielding the values of the variables of this meander and
+    /// `other` at the same intervals of `dt`, as pairs. Consumes both meanders.
+    ///
+    /// ```
+    /// use meander::typenum::{U2, U3};
+    /// use meander::Meander;
+    ///
+    /// let a: Meander<U3> = meander::rand::random();
+    /// let b: Meander<U2> = meander::rand::random();
+    /// for (i, (x, y)) in a.clone().zip_time_steps(b.clone(), 0.1).take(100).enumerate() {
+    ///     let t = i as f64 * 0.1;
+    ///     assert_eq!(x, a.evaluate(t));
+    ///     assert_eq!(y, b.evaluate(t));
+    /// }
+    /// ```
+    pub fn zip_time_steps<D2>(self, other: Meander<D2, N, W>, dt: f64)
+        -> impl Iterator<Item=(GenericArray<f64, D>, GenericArray<f64, D2>)>
+    where
+        D2: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    {
+        (0u64..).map(move |i| {
+            let t = i as f64 * dt;
+            (self.evaluate(t), other.evaluate(t))
+        })
+    }
+    /// Return an iterator yielding the values of the variables at every
+    /// `keep_every`th interval of `dt`, that is, at `t = 0`, `keep_every * dt`,
+    /// `2 * keep_every * dt`, and so on. Consumes `self`.
+    ///
+    /// Only the samples that are kept are evaluated. This is equivalent to
+    /// `into_time_steps(dt * keep_every)`, except that the times are computed from
+    /// `dt` in the same way as `into_time_steps(dt)`, so the `k`th item is exactly
+    /// the `(k * keep_every)`th item of `into_time_steps(dt)`, without the rounding
+    /// error of multiplying out `dt * keep_every`. Panics if `keep_every` is 0, and
+    /// when iterating past the point where the step number `k * keep_every` no
+    /// longer fits in a `u64`.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let decimated: Vec<_> = m.clone().decimated_time_steps(0.01, 10).take(100).collect();
+    /// let manual: Vec<_> = m.clone().into_time_steps(0.01).step_by(10).take(100).collect();
+    /// assert_eq!(decimated, manual);
+    ///
+    /// let mut sparse = m.clone().decimated_time_steps(1e-9, usize::MAX);
+    /// sparse.next();
+    /// assert_eq!(sparse.next(), Some(m.evaluate(usize::MAX as u64 as f64 * 1e-9)));
+    /// ```
+    pub fn decimated_time_steps(self, dt: f64, keep_every: usize)
+        -> impl Iterator<Item=GenericArray<f64, D>>
+    {
+        assert!(keep_every > 0, "keep_every must be positive");
+        (0u64..).map(move |i| {
+            let step = i.checked_mul(keep_every as u64).expect("step number overflowed u64");
+            self.evaluate(step as f64 * dt)
+        })
+    }
+    /// Estimate the smallest and largest values each of the variables takes, as
+    /// given by `Meander1D::estimate_range`.
+    pub fn estimate_ranges(&self, samples: usize) -> (GenericArray<f64, D>, GenericArray<f64, D>) {
+        let mut min = GenericArray::default();
+        let mut max = GenericArray::default();
+        for ((lo, hi), c) in min.iter_mut().zip(max.iter_mut()).zip(self.curves.iter()) {
+            let (a, b) = c.estimate_range(samples);
+            *lo = a;
+            *hi = b;
+        }
+        (min, max)
+    }
+    /// Score how evenly each of the variables covers `[0, 1]`, as given by
+    /// `Meander1D::coverage_score`.
+    pub fn coverage_scores(&self, samples: usize) -> GenericArray<f64, D> {
+        self.curves.iter().map(|c| c.coverage_score(samples)).collect()
+    }
+    /// Count how often the meander visits each cell of a grid dividing `[0, 1]^D`
+    /// into `bins_per_dim` bins along each axis, over `steps` intervals of `dt`
+    /// starting at time 0.
+    ///
+    /// The result is a flattened grid of `bins_per_dim.pow(D)` counts, which add up
+    /// to `steps`. The cell where variable `d` is in bin `b_d` is at index
+    /// `b_0 + b_1 * bins_per_dim + b_2 * bins_per_dim^2 + ...`. Values are clamped
+    /// to `[0, 1]` first, so values outside it land in the outermost bins. The
+    /// grid grows exponentially with `D`; this panics if its size doesn't fit in a
+    /// `usize`, or if `bins_per_dim` is 0.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U2> = meander::rand::random();
+    /// let grid = m.occupancy(0.01, 10_000, 4);
+    /// assert_eq!(grid.len(), 16);
+    /// assert_eq!(grid.iter().sum::<u32>(), 10_000);
+    /// assert!(grid.iter().filter(|&&n| n > 0).count() >= 4);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn occupancy(&self, dt: f64, steps: usize, bins_per_dim: usize) -> Vec<u32> {
+        assert!(bins_per_dim > 0, "bins_per_dim must be positive");
+        let exp = u32::try_from(self.curves.len()).expect("grid is too large");
+        let cells = bins_per_dim.checked_pow(exp).expect("grid is too large");
+        let mut grid = alloc::vec![0; cells];
+        for v in self.time_steps(dt).take(steps) {
+            let mut index = 0;
+            for &x in v.iter().rev() {
+                let bin = ((x.clamp(0.0, 1.0) * bins_per_dim as f64) as usize).min(bins_per_dim - 1);
+                index = index * bins_per_dim + bin;
+            }
+            grid[index] += 1;
+        }
+        grid
+    }
+    /// Find the straight-line (Euclidean) distance between the values of the
+    /// variables at `t0` and at `t1`.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// assert_eq!(m.distance(1.5, 1.5), 0.0);
+    /// assert_eq!(m.distance(1.0, 2.0), m.distance(2.0, 1.0));
+    /// ```
+    pub fn distance(&self, t0: f64, t1: f64) -> f64 {
+        let (a, b) = (self.evaluate(t0), self.evaluate(t1));
+        let sum: f64 = a.iter().zip(b.iter()).map(|(x, y)| (y - x) * (y - x)).sum();
+        math::sqrt(sum)
+    }
+    /// Approximate the length of the path the variables trace out between `t0` and
+    /// `t1`, by splitting it into `samples` straight segments and adding up their
+    /// lengths.
+    ///
+    /// ```
+    /// use meander::typenum::U1;
+    /// use meander::{Meander, Meander1D, UnitSinusoid};
+    ///
+    /// // This rises from 0 to 1 and falls back to 0 over one period.
+    /// let s = UnitSinusoid::new(0.5, 0.0);
+    /// let m: Meander<U1> = Meander::from_curves([Meander1D::new(s, s, s)].into());
+    /// let length = m.path_length(0.0, 2.0, 1000);
+    /// assert!(length.is_finite() && length > 0.0);
+    /// assert!((length - 2.0).abs() < 1e-6);
+    /// ```
+    pub fn path_length(&self, t0: f64, t1: f64, samples: usize) -> f64 {
+        let dt = (t1 - t0) / samples as f64;
+        (0..samples)
+            .map(|i| self.distance(t0 + i as f64 * dt, t0 + (i + 1) as f64 * dt))
+            .sum()
+    }
+    /// Find the time in `[t0, t1]` at which the sum of the values of all of the
+    /// variables is largest.
+    ///
+    /// The interval is scanned in steps of `resolution`, and the best of those
+    /// times is then refined by golden-section search over the steps on either side
+    /// of it. So the result is accurate as long as the sum has only one peak within
+    /// `resolution` of the best step; a narrower peak between two steps can be
+    /// missed. The search stops early if `f64` can't represent the times any more
+    /// precisely. Panics if `resolution` is not positive or if `t1` is less than
+    /// `t0`.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::{Meander, Meander1D, UnitSinusoid};
+    ///
+    /// // The first variable peaks at 0.5 and the second at 0.7, so their sum peaks
+    /// // halfway between them.
+    /// let a = UnitSinusoid::new(1.0, 0.0);
+    /// let b = UnitSinusoid::new(1.0, -0.2);
+    /// let m: Meander<U2> = Meander::from_curves([Meander1D::new(a, a, a), Meander1D::new(b, b, b)].into());
+    /// let t = m.peak_sum_time(0.0, 1.0, 0.03);
+    /// assert!((t - 0.6).abs() < 1e-6);
+    ///
+    /// let t = m.peak_sum_time(1e6, 1e6 + 1.0, 0.01);
+    /// assert!((t - (1e6 + 0.6)).abs() < 1e-6);
+    /// ```
+    ///
+    /// ```should_panic
+    /// use meander::typenum::U2;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U2> = meander::rand::random();
+    /// m.peak_sum_time(1.0, 0.0, 0.01);
+    /// ```
+    pub fn peak_sum_time(&self, t0: f64, t1: f64, resolution: f64) -> f64 {
+        assert!(resolution > 0.0, "resolution must be positive");
+        assert!(t0 <= t1, "t1 must not be less than t0");
+        let sum = |t: f64| -> f64 { self.evaluate(t).iter().sum() };
+        let (mut best, mut best_sum) = (t0, sum(t0));
+        let mut i = 1;
+        let mut t = t0;
+        while t < t1 {
+            t = (t0 + i as f64 * resolution).min(t1);
+            let v = sum(t);
+            if v > best_sum {
+                best = t;
+                best_sum = v;
+            }
+            i += 1;
+        }
+        let inv_phi = (math::sqrt(5.0) - 1.0) / 2.0;
+        let (mut lo, mut hi) = ((best - resolution).max(t0), (best + resolution).min(t1));
+        let mut c = hi - inv_phi * (hi - lo);
+        let mut d = lo + inv_phi * (hi - lo);
+        let (mut fc, mut fd) = (sum(c), sum(d));
+        while hi - lo > resolution * 1e-9 {
+            let width = hi - lo;
+            if fc > fd {
+                hi = d;
+                d = c;
+                fd = fc;
+                c = hi - inv_phi * (hi - lo);
+                fc = sum(c);
+            } else {
+                lo = c;
+                c = d;
+                fc = fd;
+                d = lo + inv_phi * (hi - lo);
+                fd = sum(d);
+            }
+            if hi - lo >= width {
+                break;
+            }
+        }
+        let mid = (lo + hi) / 2.0;
+        if sum(mid) >= best_sum { mid } else { best }
+    }
+    /// Measure how much each of the variables is changing around time `t`, as the
+    /// variance of `samples` evenly spaced values over `[t - window, t]`.
+    ///
+    /// A large value means the variable is swinging a lot in that window, and a
+    /// value near 0 means it is momentarily flat. If `samples` is less than 2, every
+    /// variable reports 0.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut m: Meander<U3> = meander::rand::random();
+    /// m.pin(0, 0.3);
+    /// m.curves[1] = meander::Meander1D::new(
+    ///     meander::UnitSinusoid::new(1.0, 0.0),
+    ///     meander::UnitSinusoid::new(1.3, 0.2),
+    ///     meander::UnitSinusoid::new(0.7, 0.5),
+    /// );
+    /// let activity = m.activity(10.0, 2.0, 100);
+    /// assert!(activity[0].abs() < 1e-12);
+    /// assert!(activity[1] > 0.001);
+    /// ```
+    pub fn activity(&self, t: f64, window: f64, samples: usize) -> GenericArray<f64, D> {
+        let mut mean: GenericArray<f64, D> = GenericArray::default();
+        let mut m2: GenericArray<f64, D> = GenericArray::default();
+        if samples < 2 {
+            return m2;
+        }
+        let dt = window / (samples - 1) as f64;
+        for i in 0..samples {
+            let k = (i + 1) as f64;
+            let v = self.evaluate(t - window + i as f64 * dt);
+            for ((mu, acc), x) in mean.iter_mut().zip(m2.iter_mut()).zip(v) {
+                let delta = x - *mu;
+                *mu += delta / k;
+                *acc += delta * (x - *mu);
+            }
+        }
+        m2.map(|acc| acc / samples as f64)
+    }
+    /// Find the values of the variables of this meander and `other` at a
+    /// particular point in time, and linearly interpolate between them.
+    ///
+    /// When `alpha` is 0, this is `self.evaluate(t)`, and when it is 1, this is
+    /// `other.evaluate(t)`. This can be used to crossfade between two meanders.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let a: Meander<U3> = meander::rand::random();
+    /// let b: Meander<U3> = meander::rand::random();
+    /// assert_eq!(a.blended_evaluate(&b, 1.5, 0.0), a.evaluate(1.5));
+    /// assert_eq!(a.blended_evaluate(&b, 1.5, 1.0), b.evaluate(1.5));
+    /// ```
+    pub fn blended_evaluate(&self, other: &Self, t: f64, alpha: f64) -> GenericArray<f64, D> {
+        let mut out = self.evaluate(t);
+        for (v, w) in out.iter_mut().zip(other.evaluate(t)) {
+            *v = (1.0 - alpha) * *v + alpha * w;
+        }
+        out
+    }
+    /// Return an iterator yielding the values of the variables at each of the given
+    /// points in time.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let values: Vec<_> = m.evaluate_at(vec![0.0, 0.5, 0.5, 2.0]).collect();
+    /// assert_eq!(values.len(), 4);
+    /// assert_eq!(values[1], values[2]);
+    /// assert_eq!(values[3], m.evaluate(2.0));
+    /// ```
+    pub fn evaluate_at<'a, I>(&'a self, times: I) -> impl Iterator<Item=GenericArray<f64, D>> + 'a
+    where
+        I: IntoIterator<Item=f64>,
+        I::IntoIter: 'a,
+    {
+        times.into_iter().map(move |t| self.evaluate(t))
+    }
+    /// Like `time_steps`, but each item is paired with the time at which the
+    /// variables were evaluated.
+    pub fn time_steps_enumerated<'a>(&'a self, dt: f64)
+        -> impl Iterator<Item=(f64, GenericArray<f64, D>)> + 'a
+    {
+        (0u64..).map(move |i| {
+            let t = i as f64 * dt;
+            (t, self.evaluate(t))
+        })
+    }
+    /// Like `into_time_steps`, but each item is paired with the time at which the
+    /// variables were evaluated.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U2> = meander::rand::random();
+    /// let dt = 0.1;
+    /// let steps: Vec<_> = m.clone().into_time_steps_enumerated(dt).take(10).collect();
+    /// assert_eq!(steps[0].0, 0.0);
+    /// assert_eq!(steps[9].0, 9.0 * dt);
+    /// assert_eq!(steps[9].1, m.evaluate(9.0 * dt));
+    /// ```
+    pub fn into_time_steps_enumerated(self, dt: f64)
+        -> impl Iterator<Item=(f64, GenericArray<f64, D>)>
+    {
+        (0u64..).map(move |i| {
+            let t = i as f64 * dt;
+            (t, self.evaluate(t))
+        })
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// scaled from `[0, 1]` to `[min, max]`.
+    ///
+    /// ```
+    /// use meander::generic_array::arr;
+    /// use meander::typenum::U2;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U2> = meander::rand::random();
+    /// let raw = m.evaluate(0.75);
+    /// let scaled = m.evaluate_scaled(0.75, -1.0, 1.0);
+    /// assert_eq!(scaled[0], -1.0 + raw[0] * 2.0);
+    ///
+    /// let scaled = m.evaluate_scaled_each(0.75, &arr![f64; -1.0, 0.0], &arr![f64; 1.0, 256.0]);
+    /// assert_eq!(scaled[0], -1.0 + raw[0] * 2.0);
+    /// assert_eq!(scaled[1], raw[1] * 256.0);
+    /// ```
+    pub fn evaluate_scaled(&self, t: f64, min: f64, max: f64) -> GenericArray<f64, D> {
+        self.evaluate(t).map(|v| min + v * (max - min))
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// with each variable scaled from `[0, 1]` to its own range.
+    pub fn evaluate_scaled_each(
+        &self,
+        t: f64,
+        min: &GenericArray<f64, D>,
+        max: &GenericArray<f64, D>,
+    ) -> GenericArray<f64, D> {
+        let mut out = self.evaluate(t);
+        for ((v, &lo), &hi) in out.iter_mut().zip(min.iter()).zip(max.iter()) {
+            *v = lo + *v * (hi - lo);
+        }
+        out
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// scaled from `[0, 1]` to `[min, max]`. Consumes `self`.
+    pub fn scaled_time_steps(self, dt: f64, min: f64, max: f64)
+        -> impl Iterator<Item=GenericArray<f64, D>>
+    {
+        (0u64..).map(move |i| self.evaluate_scaled(i as f64 * dt, min, max))
+    }
+    /// Return an iterator yielding the values of the variables at `subdivisions`
+    /// evenly spaced points within each interval of `dt`.
+    ///
+    /// Every point is found by evaluating the meander directly, rather than by
+    /// interpolating between samples, so this is as accurate as `time_steps` with a
+    /// step of `dt / subdivisions`. The first point of each group of `subdivisions`
+    /// is the same as the corresponding item of `time_steps(dt)`. Panics if
+    /// `subdivisions` is 0.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U2> = meander::rand::random();
+    /// let fine: Vec<_> = m.resample_smooth(0.5, 4).take(10 * 4).collect();
+    /// let coarse: Vec<_> = m.time_steps(0.5).take(10).collect();
+    /// assert_eq!(fine.len(), 4 * coarse.len());
+    /// for (i, v) in coarse.iter().enumerate() {
+    ///     assert_eq!(&fine[4 * i], v);
+    /// }
+    /// assert_eq!(fine[6], m.evaluate((1.0 + 2.0 / 4.0) * 0.5));
+    /// ```
+    pub fn resample_smooth<'a>(&'a self, dt: f64, subdivisions: usize)
+        -> impl Iterator<Item=GenericArray<f64, D>> + 'a
+    {
+        assert!(subdivisions > 0, "subdivisions must be positive");
+        (0u64..).flat_map(move |i| (0..subdivisions).map(move |k| {
+            self.evaluate((i as f64 + k as f64 / subdivisions as f64) * dt)
+        }))
+    }
+    /// Find the value of each of the variables at a particular point in time, with
+    /// the meander sped up by a factor of `speed`. This is `evaluate(t * speed)`.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// assert_eq!(m.evaluate_at_speed(1.25, 2.0), m.evaluate(2.5));
+    /// let steps: Vec<_> = m.time_steps_at_speed(0.1, 2.0).take(5).collect();
+    /// assert_eq!(steps[4], m.evaluate(4.0 * 0.1 * 2.0));
+    /// ```
+    pub fn evaluate_at_speed(&self, t: f64, speed: f64) -> GenericArray<f64, D> {
+        self.evaluate(t * speed)
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// with the meander sped up by a factor of `speed`.
+    pub fn time_steps_at_speed<'a>(&'a self, dt: f64, speed: f64)
+        -> impl Iterator<Item=GenericArray<f64, D>> + 'a
+    {
+        (0u64..).map(move |i| self.evaluate_at_speed(i as f64 * dt, speed))
+    }
+    /// Find the value of each of the variables at a particular point in time, with
+    /// playback over `[0, duration]` eased in and out.
+    ///
+    /// `t` is remapped through the smoothstep function `3x² - 2x³` (with `x` being
+    /// `t / duration`) before evaluating, so the meander starts from rest at
+    /// `t = 0`, speeds up, and slows back to rest at `t = duration`, where it has
+    /// the same value as `evaluate(duration)`. Outside `[0, duration]`, `t` is
+    /// clamped to the nearest end. `duration` must be positive.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let (d, h) = (4.0, 1e-6);
+    /// assert_eq!(m.evaluate_eased(0.0, d), m.evaluate(0.0));
+    /// assert_eq!(m.evaluate_eased(d, d), m.evaluate(d));
+    /// assert_eq!(m.evaluate_eased(-1.0, d), m.evaluate(0.0));
+    /// assert_eq!(m.evaluate_eased(d + 1.0, d), m.evaluate(d));
+    /// for i in 0..3 {
+    ///     let start = (m.evaluate_eased(h, d)[i] - m.evaluate_eased(0.0, d)[i]) / h;
+    ///     let end = (m.evaluate_eased(d, d)[i] - m.evaluate_eased(d - h, d)[i]) / h;
+    ///     assert!(start.abs() < 1e-3 && end.abs() < 1e-3);
+    /// }
+    /// ```
+    pub fn evaluate_eased(&self, t: f64, duration: f64) -> GenericArray<f64, D> {
+        let x = (t / duration).clamp(0.0, 1.0);
+        self.evaluate(x * x * (3.0 - 2.0 * x) * duration)
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// clamped to `[0, 1]`.
+    ///
+    /// The values returned by `evaluate` can lie outside `[0, 1]` if the `amplitude`
+    /// or `offset` of a component has been changed, or if a custom `Waveform`
+    /// returns values outside `[0, 1]` (which rounding error in its calculations can
+    /// easily cause). This guarantees that they don't, which is useful when the
+    /// values are used to index into a table.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::{Meander, Meander1D, UnitSinusoid};
+    ///
+    /// let loud = UnitSinusoid { amplitude: 1.5, ..UnitSinusoid::new(1.0, 0.0) };
+    /// let c = Meander1D::new(loud, loud, loud);
+    /// let m: Meander<U2> = Meander::from_curves([c.clone(), c].into());
+    /// assert!(m.evaluate(0.5)[0] > 1.0);
+    /// assert_eq!(m.evaluate_clamped(0.5)[0], 1.0);
+    /// for v in m.into_clamped_time_steps(0.01).take(1000) {
+    ///     assert!(v.iter().all(|&v| 0.0 <= v && v <= 1.0));
+    /// }
+    /// ```
+    pub fn evaluate_clamped(&self, t: f64) -> GenericArray<f64, D> {
+        self.evaluate(t).map(|v| v.clamp(0.0, 1.0))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// clamped to `[0, 1]`. Consumes `self`.
+    pub fn into_clamped_time_steps(self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> {
+        (0u64..).map(move |i| self.evaluate_clamped(i as f64 * dt))
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// rounded to the nearest of `levels` evenly spaced points in `[0, 1]` (that is,
+    /// a multiple of `1 / (levels - 1)`).
+    ///
+    /// Values are clamped to `[0, 1]` first. With `levels = 2`, each variable
+    /// flickers between 0 and 1. Panics if `levels` is less than 2.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// for (q, v) in m.clone().into_quantized_time_steps(0.01, 2).zip(m.time_steps(0.01)).take(1000) {
+    ///     for (&q, &v) in q.iter().zip(v.iter()) {
+    ///         assert!(q == 0.0 || q == 1.0);
+    ///         assert_eq!(q == 1.0, v >= 0.5);
+    ///     }
+    /// }
+    /// for (&q, &v) in m.evaluate_quantized(1.3, 5).iter().zip(m.evaluate(1.3).iter()) {
+    ///     assert!([0.0, 0.25, 0.5, 0.75, 1.0].contains(&q));
+    ///     assert!((q - v).abs() <= 0.125);
+    /// }
+    /// ```
+    pub fn evaluate_quantized(&self, t: f64, levels: usize) -> GenericArray<f64, D> {
+        assert!(levels >= 2, "levels must be at least 2");
+        let steps = (levels - 1) as f64;
+        self.evaluate_clamped(t).map(|v| math::round(v * steps) / steps)
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// quantized as by `evaluate_quantized`. Consumes `self`.
+    pub fn into_quantized_time_steps(self, dt: f64, levels: usize)
+        -> impl Iterator<Item=GenericArray<f64, D>>
+    {
+        (0u64..).map(move |i| self.evaluate_quantized(i as f64 * dt, levels))
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// scaled so that they sum to 1. This makes the output a point on the unit
+    /// simplex, such as a probability distribution over `D` outcomes.
+    ///
+    /// Values are clamped to `[0, 1]` before normalizing, so none of the outputs are
+    /// negative. If they're all zero, each output is `1 / D`.
+    ///
+    /// Note that this changes the distribution of the output. The variables are no
+    /// longer independent (when one rises, the others fall), and points near the
+    /// middle of the simplex are much more common than points near its corners.
+    ///
+    /// ```
+    /// use meander::typenum::U4;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U4> = meander::rand::random();
+    /// for i in 0..1000 {
+    ///     let p = m.evaluate_simplex(i as f64 * 0.01);
+    ///     assert!(p.iter().all(|&x| 0.0 <= x && x <= 1.0));
+    ///     assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn evaluate_simplex(&self, t: f64) -> GenericArray<f64, D> {
+        let values = self.evaluate_clamped(t);
+        let sum: f64 = values.iter().sum();
+        if sum > 0.0 {
+            values.map(|v| v / sum)
+        } else {
+            values.map(|_| 1.0 / D::USIZE as f64)
+        }
+    }
+    /// Find the value of each of the variables at a particular point in time,
+    /// scaled so that they have a Euclidean (L2) norm of 1. This makes the output a
+    /// point on the unit sphere.
+    ///
+    /// Since the values are usually in `[0, 1]`, the output lies on the part of the
+    /// sphere where every coordinate is non-negative. If they're all zero, each
+    /// output is `1 / sqrt(D)`. As with `evaluate_simplex`, this changes the
+    /// distribution of the output.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// for i in 0..1000 {
+    ///     let p = m.evaluate_unit_sphere(i as f64 * 0.01);
+    ///     let norm = p.iter().map(|x| x * x).sum::<f64>().sqrt();
+    ///     assert!((norm - 1.0).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn evaluate_unit_sphere(&self, t: f64) -> GenericArray<f64, D> {
+        let values = self.evaluate(t);
+        let norm = math::sqrt(values.iter().map(|v| v * v).sum());
+        if norm > 0.0 {
+            values.map(|v| v / norm)
+        } else {
+            values.map(|_| 1.0 / math::sqrt(D::USIZE as f64))
+        }
+    }
+    /// Find the largest distance by which any of the variables falls outside
+    /// `[0, 1]`, sampling them `steps` times at intervals of `dt` from `t = 0`. This
+    /// is 0 if they stay in range the whole time.
+    ///
+    /// A meander made of plain sinusoids stays in `[0, 1]` up to rounding error,
+    /// but one whose sinusoids have been given a larger amplitude or a different
+    /// offset may not. This is meant as a check on that guarantee, for use in tests.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::{Meander, Meander1D, UnitSinusoid};
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// assert!(m.max_excursion(0.01, 10_000) < 1e-12);
+    ///
+    /// let s = UnitSinusoid { amplitude: 1.5, offset: -0.25, ..UnitSinusoid::new(1.0, 0.0) };
+    /// let c = Meander1D::new(s, s, s);
+    /// let loud: Meander<U3> = Meander::from([c.clone(), c.clone(), c]);
+    /// assert!((loud.max_excursion(0.5, 3) - 0.25).abs() < 1e-12);
+    /// ```
+    pub fn max_excursion(&self, dt: f64, steps: usize) -> f64 {
+        let mut max: f64 = 0.0;
+        for i in 0..steps {
+            for &v in self.evaluate(i as f64 * dt).iter() {
+                max = max.max(-v).max(v - 1.0);
+            }
+        }
+        max
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`,
+    /// scaled from `[0, 1]` to `[0, 255]` and rounded to the nearest integer.
+    /// Consumes `self`.
+    ///
+    /// Values are clamped to `[0, 1]` before scaling, so rounding error can never
+    /// push a value out of the range of `u8`.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::{Meander, Meander1D, UnitSinusoid};
+    ///
+    /// let m: Meander<U2> = meander::rand::random();
+    /// let expected = m.clone().into_time_steps(0.01);
+    /// for (bytes, v) in m.into_u8_steps(0.01).zip(expected).take(1000) {
+    ///     for (&b, &v) in bytes.iter().zip(v.iter()) {
+    ///         assert!((b as f64 - v * 255.0).abs() <= 0.5 + 1e-9);
+    ///     }
+    /// }
+    ///
+    /// // A haversine starts a cycle at 0 and is 1 halfway through.
+    /// let s = UnitSinusoid::new(1.0, 0.0);
+    /// let c = Meander1D::new(s, s, s);
+    /// let m: Meander<U2> = Meander::from_curves([c.clone(), c].into());
+    /// let steps: Vec<_> = m.into_u8_steps(0.5).take(2).collect();
+    /// assert_eq!(steps[0].as_slice(), &[0, 0]);
+    /// assert_eq!(steps[1].as_slice(), &[255, 255]);
+    /// ```
+    pub fn into_u8_steps(self, dt: f64) -> impl Iterator<Item=GenericArray<u8, D>>
+    where
+        D: ArrayLength<u8>,
+    {
+        (0u64..).map(move |i| {
+            self.evaluate(i as f64 * dt)
+                .map(|v| math::round(v.clamp(0.0, 1.0) * 255.0) as u8)
+        })
+    }
+    /// Wrap this meander in a `MappedMeander`, which applies `f` to the value of
+    /// every variable it produces. Consumes `self`.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let squared = m.clone().map_output(|x| x * x);
+    /// for (a, b) in squared.time_steps(0.1).zip(m.time_steps(0.1)).take(100) {
+    ///     for i in 0..3 {
+    ///         assert_eq!(a[i], b[i] * b[i]);
+    ///     }
+    /// }
+    /// ```
+    pub fn map_output<F: Fn(f64) -> f64 + Clone>(self, f: F) -> MappedMeander<D, F, N, W> {
+        MappedMeander { meander: self, f }
+    }
+    /// Turn this meander into a function from a point in time to the values of the
+    /// variables at that time, for passing to code that expects a closure.
+    ///
+    /// The function is `Send` and `Sync` whenever the meander is.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// fn assert_send_sync<T: Send + Sync>(_: &T) {}
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let f = m.clone().into_fn();
+    /// assert_send_sync(&f);
+    /// for &t in &[0.0, 0.3, 1.7, 42.0] {
+    ///     assert_eq!(f(t), m.evaluate(t));
+    /// }
+    /// ```
+    pub fn into_fn(self) -> impl Fn(f64) -> GenericArray<f64, D> {
+        move |t| self.evaluate(t)
+    }
+}
+
+impl<D, N, W> Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// Create a meander from the curves for each of its variables.
+    ///
+    /// ```
+    /// use meander::generic_array::arr;
+    /// use meander::{Meander, Meander1D, UnitSinusoid};
+    ///
+    /// let hue = Meander1D::new(
+    ///     UnitSinusoid::new(1.0, 0.0),
+    ///     UnitSinusoid::new(2.0, 0.25),
+    ///     UnitSinusoid::new(3.0, 0.5),
+    /// );
+    /// let brightness = Meander1D::new(
+    ///     UnitSinusoid::new(0.5, 0.0),
+    ///     UnitSinusoid::new(0.5, 0.0),
+    ///     UnitSinusoid::new(0.5, 0.0),
+    /// );
+    /// let m = Meander::from_curves(arr![Meander1D; hue, brightness]);
+    ///
+    /// let values = m.evaluate(0.0);
+    /// assert!((values[0] - 2.0 / 3.0).abs() < 1e-12);
+    /// assert_eq!(values[1], 0.0);
+    /// ```
+    pub fn from_curves(curves: GenericArray<Meander1D<N, W>, D>) -> Self {
+        Meander { curves }
+    }
+    /// Combine two meanders into one, whose first `D` variables are controlled by
+    /// the curves of `self` and whose remaining `D2` variables are controlled by
+    /// the curves of `other`.
+    ///
+    /// ```
+    /// use meander::typenum::{U2, U3};
+    /// use meander::Meander;
+    ///
+    /// let color: Meander<U3> = meander::rand::random();
+    /// let position: Meander<U2> = meander::rand::random();
+    /// let both = color.clone().concat(position.clone());
+    /// let values = both.evaluate(0.7);
+    /// assert_eq!(values.len(), 5);
+    /// assert_eq!(&values[..3], color.evaluate(0.7).as_slice());
+    /// assert_eq!(&values[3..], position.evaluate(0.7).as_slice());
+    /// ```
+    pub fn concat<D2>(self, other: Meander<D2, N, W>) -> Meander<Sum<D, D2>, N, W>
+    where
+        D: Add<D2>,
+        D2: ArrayLength<Meander1D<N, W>>,
+        Sum<D, D2>: ArrayLength<Meander1D<N, W>>,
+    {
+        Meander { curves: self.curves.concat(other.curves) }
+    }
+    /// Create a meander from a subset of the variables of this one, in the given
+    /// order.
+    ///
+    /// Panics if any of the indices is not less than `D`.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let p = m.project(&[2, 0]);
+    /// let (full, projected) = (m.evaluate(0.3), p.evaluate(0.3));
+    /// assert_eq!(projected, vec![full[2], full[0]]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn project(&self, indices: &[usize]) -> DynMeander<N, W>
+    where
+        W: Clone,
+    {
+        let curves = indices.iter().map(|&i| {
+            assert!(
+                i < self.curves.len(),
+                "index {} is out of range for a meander with {} variables", i, self.curves.len(),
+            );
+            self.curves[i].clone()
+        }).collect();
+        DynMeander { curves }
+    }
+    /// The number of variables, `D`.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m = Meander::<U3>::default();
+    /// assert_eq!(m.dimensions(), 3);
+    /// assert_eq!(m.len(), 3);
+    /// assert!(!m.is_empty());
+    /// ```
+    pub fn dimensions(&self) -> usize {
+        D::to_usize()
+    }
+    /// The number of variables, `D`. This is the same as `dimensions`.
+    pub fn len(&self) -> usize {
+        D::to_usize()
+    }
+    /// Whether this meander has no variables.
+    pub fn is_empty(&self) -> bool {
+        D::to_usize() == 0
+    }
+    /// Return a meander that moves `factor` times as fast as this one, so that
+    /// `m.time_scaled(factor).evaluate(t)` is `m.evaluate(t * factor)`.
+    ///
+    /// `factor` must be positive.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// for &factor in &[0.5, 2.0, 3.7] {
+    ///     let scaled = m.clone().time_scaled(factor);
+    ///     for &t in &[0.0, 0.25, 1.0, 10.0] {
+    ///         let (a, b) = (scaled.evaluate(t), m.evaluate(t * factor));
+    ///         for i in 0..3 {
+    ///             assert!((a[i] - b[i]).abs() < 1e-9);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn time_scaled(mut self, factor: f64) -> Self {
+        for curve in self.curves.iter_mut() {
+            for s in curve.components.iter_mut() {
+                s.frequency *= factor;
+                s.phase /= factor;
+            }
+            curve.envelope.frequency *= factor;
+            curve.envelope.phase /= factor;
+        }
+        self
+    }
+    /// Mirror variable `dim` around 0.5, so that its value at every point in time
+    /// becomes `1.0` minus what it was (up to rounding error).
+    ///
+    /// This is done by negating the amplitude of each component and subtracting
+    /// its offset from 1. It can be used to make one variable fall while another
+    /// rises. If the curve has an envelope, only the average of the components is
+    /// mirrored, and it is still multiplied by the envelope afterward.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let original: Meander<U3> = meander::rand::random();
+    /// let mut m = original.clone();
+    /// m.invert_dimension(0);
+    /// for (a, b) in m.time_steps(0.05).zip(original.time_steps(0.05)).take(200) {
+    ///     assert!((a[0] - (1.0 - b[0])).abs() < 1e-12);
+    ///     assert_eq!(a[1], b[1]);
+    /// }
+    /// ```
+    pub fn invert_dimension(&mut self, dim: usize) {
+        for s in self.curves[dim].components.iter_mut() {
+            s.amplitude = -s.amplitude;
+            s.offset = 1.0 - s.offset;
+        }
+    }
+    /// Slow down every component sinusoid whose frequency is greater than
+    /// `max_freq` to have a frequency of exactly `max_freq`.
+    ///
+    /// The phase of each such component is adjusted so that its value at `t = 0`
+    /// stays the same (up to rounding error). This bounds how fast the variables
+    /// can move without having to sample a new meander. `max_freq` must be
+    /// positive.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let original: Meander<U3> = meander::rand::random();
+    /// let mut m = original.clone();
+    /// m.clamp_frequency(4.0);
+    /// for curve in m.curves.iter() {
+    ///     assert!(curve.components.iter().all(|s| s.frequency <= 4.0));
+    /// }
+    /// let (a, b) = (m.evaluate(0.0), original.evaluate(0.0));
+    /// for i in 0..3 {
+    ///     assert!((a[i] - b[i]).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn clamp_frequency(&mut self, max_freq: f64) {
+        for curve in self.curves.iter_mut() {
+            for s in curve.components.iter_mut() {
+                if s.frequency > max_freq {
+                    let cycles = s.frequency * s.phase;
+                    s.frequency = max_freq;
+                    s.phase = (cycles - math::floor(cycles)) / max_freq;
+                }
+            }
+        }
+    }
+    /// Like `clamp_frequency`, but consumes `self` and returns the result.
+    pub fn with_max_frequency(mut self, max_freq: f64) -> Self {
+        self.clamp_frequency(max_freq);
+        self
+    }
+    /// Resample the phase of every component sinusoid uniformly from
+    /// `0.0..frequency.recip()`, leaving the frequencies untouched.
+    ///
+    /// This changes where in their cycles the variables are without changing how
+    /// quickly they move. Every frequency must be positive.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let original: Meander<U3> = meander::rand::random();
+    /// let mut m = original.clone();
+    /// m.rephase(&mut rng);
+    /// let mut changed = 0;
+    /// for (a, b) in m.curves.iter().zip(original.curves.iter()) {
+    ///     for (s, t) in a.components.iter().zip(b.components.iter()) {
+    ///         assert_eq!(s.frequency, t.frequency);
+    ///         assert!(0.0 <= s.phase && s.phase < s.frequency.recip());
+    ///         if s.phase != t.phase {
+    ///             changed += 1;
+    ///         }
+    ///     }
+    /// }
+    /// assert!(changed > 0);
+    /// ```
+    pub fn rephase<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for curve in self.curves.iter_mut() {
+            for s in curve.components.iter_mut() {
+                s.phase = rng.gen_range(0.0, s.frequency.recip());
+            }
+        }
+    }
+    /// Find the smallest positive time after which the meander repeats itself.
+    ///
+    /// This is `None` unless the frequencies of all of the component sinusoids are
+    /// (approximately) rational multiples of each other, which is usually the case
+    /// only for meanders created by `random_looping`. The same tolerance is used as
+    /// by `Meander1D::period`.
+    pub fn period(&self) -> Option<f64> {
+        period::common_period(self.curves.iter().flat_map(|c| c.frequencies()))
+    }
+    /// Find the dominant frequency of each of the variables, as given by
+    /// `Meander1D::dominant_frequency`.
+    pub fn dominant_frequencies(&self) -> GenericArray<f64, D>
+    where
+        D: ArrayLength<f64>,
+    {
+        self.curves.iter().map(|c| c.dominant_frequency()).collect()
+    }
+    /// Describe the frequency and phase of each component of each variable in a
+    /// table, for debugging or sharing.
+    ///
+    /// There is a header row, followed by a row for each variable with its index
+    /// and then the frequency and phase of each of its components, separated by
+    /// tabs. Each curve's `envelope`, which multiplies its output, is left out, as
+    /// are the amplitude, offset and weight of each component. This is meant to be
+    /// read by people; use `serde` to save a meander exactly.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let table = m.describe();
+    /// let lines: Vec<&str> = table.lines().collect();
+    /// assert_eq!(lines[0], "dim\tfreq0\tphase0\tfreq1\tphase1\tfreq2\tphase2");
+    /// for (i, curve) in m.curves.iter().enumerate() {
+    ///     assert!(lines[i + 1].starts_with(&format!("{}\t", i)));
+    ///     for s in curve.components.iter() {
+    ///         assert!(lines[i + 1].contains(&s.frequency.to_string()));
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn describe(&self) -> String {
+        use core::fmt::Write;
+        let mut out = String::from("dim");
+        for i in 0..N::to_usize() {
+            let _ = write!(out, "\tfreq{}\tphase{}", i, i);
+        }
+        for (i, curve) in self.curves.iter().enumerate() {
+            let _ = write!(out, "\n{}", i);
+            for s in curve.components.iter() {
+                let _ = write!(out, "\t{}\t{}", s.frequency, s.phase);
+            }
+        }
+        out.push('\n');
+        out
+    }
+}
+
+impl<D, N, W> Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    /// Generate a random meander that repeats itself exactly every `period` units
+    /// of time, which must be positive.
+    ///
+    /// This is done by rounding the frequency of each component sinusoid to the
+    /// nearest (nonzero) multiple of `1.0 / period`.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let m: Meander<U3> = Meander::random_looping(2.5, &mut rng);
+    /// let (start, end) = (m.evaluate(0.0), m.evaluate(2.5));
+    /// for i in 0..3 {
+    ///     assert!((start[i] - end[i]).abs() < 1e-9);
+    /// }
+    /// let period = m.period().unwrap();
+    /// assert!((2.5 / period - (2.5 / period).round()).abs() < 1e-9);
+    /// ```
+    pub fn random_looping<R: Rng + ?Sized>(period: f64, rng: &mut R) -> Self {
+        let mut meander: Self = rng.gen();
+        for curve in meander.curves.iter_mut() {
+            for s in curve.components.iter_mut() {
+                let cycles = math::round(s.frequency * period).max(1.0);
+                s.frequency = cycles / period;
+                s.phase = rng.gen_range(0.0, s.frequency.recip());
+            }
+        }
+        meander
+    }
+    /// Generate a random meander whose variables tend to move together.
+    ///
+    /// A single shared curve is sampled along with an independent curve for each
+    /// variable. The frequency and phase of each component of a variable's curve are
+    /// then linearly interpolated between the independent curve (when
+    /// `correlation` is 0) and the shared curve (when `correlation` is 1). So
+    /// with a `correlation` of 0 the variables are independent, as they are with
+    /// `rand::random()`, and with a `correlation` of 1 they are all identical.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let m: Meander<U3> = Meander::random_correlated(1.0, &mut rng);
+    /// for v in m.time_steps(0.01).take(1000) {
+    ///     assert_eq!(v[0], v[1]);
+    ///     assert_eq!(v[1], v[2]);
+    /// }
+    /// ```
+    pub fn random_correlated<R: Rng + ?Sized>(correlation: f64, rng: &mut R) -> Self {
+        let shared: Meander1D<N, W> = rng.gen();
+        let mut meander: Self = rng.gen();
+        for curve in meander.curves.iter_mut() {
+            for (s, base) in curve.components.iter_mut().zip(shared.components.iter()) {
+                s.frequency = (1.0 - correlation) * s.frequency + correlation * base.frequency;
+                s.phase = (1.0 - correlation) * s.phase + correlation * base.phase;
+            }
+        }
+        meander
+    }
+    /// Generate a random meander where each variable's frequencies are sampled
+    /// from its own range.
+    ///
+    /// The components of the curve for variable `i` are sampled from
+    /// `UnitSinusoidDist` with `freq_min` and `freq_max` given by `ranges[i]`. This
+    /// can be used to make some variables drift slowly while others move quickly.
+    ///
+    /// Panics if any `freq_min` is not positive or is not less than its `freq_max`.
+    ///
+    /// ```
+    /// use meander::generic_array::arr;
+    /// use meander::typenum::U2;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// for _ in 0..100 {
+    ///     let ranges = arr![(f64, f64); (0.1, 0.5), (5.0, 20.0)];
+    ///     let m: Meander<U2> = Meander::random_with_ranges(ranges, &mut rng);
+    ///     for s in m.curves[0].components.iter() {
+    ///         assert!(0.1 <= s.frequency && s.frequency < 0.5);
+    ///     }
+    ///     for s in m.curves[1].components.iter() {
+    ///         assert!(5.0 <= s.frequency && s.frequency < 20.0);
+    ///     }
+    /// }
+    /// ```
+    pub fn random_with_ranges<R: Rng + ?Sized>(
+        ranges: GenericArray<(f64, f64), D>,
+        rng: &mut R,
+    ) -> Self
+    where
+        D: ArrayLength<(f64, f64)>,
+    {
+        let curves = ranges.map(|(freq_min, freq_max)| {
+            assert!(
+                freq_min > 0.0 && freq_min < freq_max,
+                "invalid frequency range {}..{}", freq_min, freq_max,
+            );
+            let dist = UnitSinusoidDist { freq_min, freq_max };
+            Meander1D { components: GenericArray::generate(|_| rng.sample(dist)), envelope: default_envelope() }
+        });
+        Meander { curves }
+    }
+    /// Generate a random meander whose curves each have a random envelope, with a
+    /// frequency sampled from `envelope_freq_range`.
+    ///
+    /// The curves are sampled as if by `rand::random()`, and their envelopes from
+    /// `UnitSinusoidDist` with `freq_min` and `freq_max` given by
+    /// `envelope_freq_range`. A low range makes the variables swell and fade slowly
+    /// on top of their usual motion.
+    ///
+    /// Panics if `freq_min` is not positive or is not less than `freq_max`.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::{Meander, UnitSinusoid};
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let m: Meander<U3> = Meander::random_with_envelope((0.01, 0.05), &mut rng);
+    /// for c in m.curves.iter() {
+    ///     let e = c.envelope;
+    ///     assert!(0.01 <= e.frequency && e.frequency < 0.05);
+    ///
+    ///     let mut plain = c.clone();
+    ///     plain.envelope = UnitSinusoid::constant(1.0);
+    ///     for &t in &[0.0, 1.3, 20.0, 75.5] {
+    ///         assert!((c.evaluate(t) - plain.evaluate(t) * e.evaluate(t)).abs() < 1e-12);
+    ///     }
+    ///
+    ///     // The default envelope stays at 1, so it changes nothing.
+    ///     let original: meander::Meander1D = meander::rand::random();
+    ///     let mut flat = original.clone();
+    ///     flat.envelope = UnitSinusoid { frequency: 0.02, phase: 3.0, ..UnitSinusoid::constant(1.0) };
+    ///     for &t in &[0.0, 1.3, 20.0, 75.5] {
+    ///         assert_eq!(flat.evaluate(t), original.evaluate(t));
+    ///         assert_eq!(flat.derivative(t), original.derivative(t));
+    ///     }
+    ///
+    ///     let h = 1e-6;
+    ///     let approx = (c.evaluate(3.0 + h) - c.evaluate(3.0 - h)) / (2.0 * h);
+    ///     assert!((c.derivative(3.0) - approx).abs() < 1e-6);
+    ///
+    ///     let n = 100_000;
+    ///     let numerical: f64 = (0..n)
+    ///         .map(|i| c.evaluate(1.0 + (i as f64 + 0.5) * 30.0 / n as f64))
+    ///         .sum::<f64>() / n as f64;
+    ///     assert!((c.average_over(1.0, 31.0) - numerical).abs() < 1e-5);
+    ///
+    ///     let (dc, terms) = c.as_cosine_sum();
+    ///     let t = 12.25;
+    ///     let value: f64 = dc + terms.iter()
+    ///         .map(|&(a, f, p)| a * (2.0 * std::f64::consts::PI * f * (t + p)).cos())
+    ///         .sum::<f64>();
+    ///     assert!((value - c.evaluate(t)).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn random_with_envelope<R: Rng + ?Sized>(envelope_freq_range: (f64, f64), rng: &mut R) -> Self {
+        let (freq_min, freq_max) = envelope_freq_range;
+        assert!(
+            freq_min > 0.0 && freq_min < freq_max,
+            "invalid frequency range {}..{}", freq_min, freq_max,
+        );
+        let dist = UnitSinusoidDist { freq_min, freq_max };
+        let mut m: Self = rng.gen();
+        for c in m.curves.iter_mut() {
+            c.envelope = rng.sample(dist);
+        }
+        m
+    }
+    /// Generate a meander deterministically from a seed.
+    ///
+    /// The meander is sampled as if by `rand::random()`, but using a `StdRng`
+    /// (which is HC-128 in the version of `rand` used by this crate) created with
+    /// `SeedableRng::seed_from_u64(seed)`. The same seed always produces the same
+    /// meander; changing the RNG used here is considered a breaking change.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let a: Meander<U3> = Meander::from_seed(42);
+    /// assert_eq!(a, Meander::from_seed(42));
+    /// assert_ne!(a, Meander::from_seed(43));
+    /// ```
+    pub fn from_seed(seed: u64) -> Self {
+        StdRng::seed_from_u64(seed).gen()
+    }
+    /// Generate a random meander whose variables each sweep out nearly all of
+    /// `[0, 1]`, so that over time it passes close to every corner of `[0, 1]^D`.
+    ///
+    /// Averaging several sinusoids means the variables of an ordinary random
+    /// meander rarely get near 0 or 1. This samples a meander as if by
+    /// `rand::random()`, estimates the range of each curve with
+    /// `Meander1D::estimate_range`, and then adjusts the amplitudes and offsets of
+    /// its components so that the estimated range is stretched to exactly `[0, 1]`.
+    /// Since the estimate only samples the curve, the true range can be slightly
+    /// wider; use `evaluate_clamped` if that matters.
+    ///
+    /// Each variable reaches its extremes independently of the others, so reaching
+    /// a particular corner depends on all of them lining up at once. There are `2^D`
+    /// corners, so for large `D` this can take a very long time.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = Meander::random_tour(&mut meander::rand::thread_rng());
+    /// let mut min = [1.0f64; 3];
+    /// let mut max = [0.0f64; 3];
+    /// for v in m.time_steps(0.01).take(100_000) {
+    ///     for i in 0..3 {
+    ///         min[i] = min[i].min(v[i]);
+    ///         max[i] = max[i].max(v[i]);
+    ///     }
+    /// }
+    /// for i in 0..3 {
+    ///     assert!(min[i] < 0.1 && max[i] > 0.9);
+    /// }
+    /// ```
+    pub fn random_tour<R: Rng + ?Sized>(rng: &mut R) -> Self
+    where
+        W: Waveform,
+    {
+        let mut m: Self = rng.gen();
+        for c in m.curves.iter_mut() {
+            let (lo, hi) = c.estimate_range(10_000);
+            let span = hi - lo;
+            if span.is_nan() || span <= 1e-9 {
+                continue;
+            }
+            for s in c.components.iter_mut() {
+                s.amplitude /= span;
+                s.offset = (s.offset - lo) / span;
+            }
+        }
+        m
+    }
+    /// Generate a meander deterministically from one seed per variable.
+    ///
+    /// The curve of variable `i` is sampled as if by `rand::random()`, using a
+    /// `StdRng` created from `seeds[i]` in the same way as `from_seed`. So each curve
+    /// depends only on its own seed, and a single variable can be regenerated
+    /// without knowing the seeds of the others.
+    ///
+    /// ```
+    /// use meander::generic_array::arr;
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let a: Meander<U3> = Meander::from_seeds(arr![u64; 7, 1, 2]);
+    /// let b: Meander<U3> = Meander::from_seeds(arr![u64; 7, 3, 4]);
+    /// for (x, y) in a.time_steps(0.1).zip(b.time_steps(0.1)).take(100) {
+    ///     assert_eq!(x[0], y[0]);
+    /// }
+    /// assert!(a.time_steps(0.1).zip(b.time_steps(0.1)).take(100).any(|(x, y)| x[1] != y[1]));
+    /// assert!(a.time_steps(0.1).zip(b.time_steps(0.1)).take(100).any(|(x, y)| x[2] != y[2]));
+    /// ```
+    pub fn from_seeds(seeds: GenericArray<u64, D>) -> Self
+    where
+        D: ArrayLength<u64>,
+    {
+        let curves = seeds.iter().map(|&seed| StdRng::seed_from_u64(seed).gen()).collect();
+        Meander { curves }
+    }
+    /// Generate a random meander none of whose curves are degenerate, in the sense of
+    /// `Meander1D::is_degenerate(threshold)`.
+    ///
+    /// This samples meanders as if by `rand::random()` until one is found, so
+    /// `threshold` should be small compared to the range of frequencies (see
+    /// `Meander1D::is_degenerate` for a sensible value). Panics if `N` is less
+    /// than 2, since every curve with a single component is degenerate.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// for _ in 0..1000 {
+    ///     let m: Meander<U3> = Meander::random_nondegenerate(0.5, &mut rng);
+    ///     assert!(m.curves.iter().all(|c| !c.is_degenerate(0.5)));
+    /// }
+    /// ```
+    pub fn random_nondegenerate<R: Rng + ?Sized>(threshold: f64, rng: &mut R) -> Self {
+        assert!(N::to_usize() >= 2, "curves must have at least 2 components");
+        loop {
+            let meander: Self = rng.gen();
+            if meander.curves.iter().all(|c| !c.is_degenerate(threshold)) {
+                return meander;
+            }
+        }
+    }
+    /// Generate a random meander that doesn't repeat itself for at least
+    /// `min_period` units of time, and none of whose variables do either.
+    ///
+    /// This samples meanders as if by `rand::random()` until one is found whose
+    /// `period`, and the `Meander1D::period` of each of whose curves, is either
+    /// `None` or at least `min_period`. Frequencies sampled at random are almost
+    /// never rational multiples of each other, so the first sample nearly always
+    /// qualifies; this makes the guarantee explicit rather than leaving it to
+    /// chance.
+    ///
+    /// A meander whose frequencies are truly irrational multiples of each other
+    /// never repeats exactly, but it can come close. The goal here is that the
+    /// motion doesn't visibly cycle: for the components of a variable to all line
+    /// up again at once, every ratio between their frequencies has to be close to a
+    /// fraction with the same small denominator, which becomes very unlikely as the
+    /// number of components grows.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let hour = 3600.0;
+    /// for _ in 0..100 {
+    ///     let m: Meander<U3> = Meander::random_min_period(hour, &mut rng);
+    ///     assert!(m.period().map_or(true, |p| p >= hour));
+    ///     assert!(m.curves.iter().all(|c| c.period().map_or(true, |p| p >= hour)));
+    /// }
+    /// ```
+    pub fn random_min_period<R: Rng + ?Sized>(min_period: f64, rng: &mut R) -> Self {
+        // `Option::is_none_or` would be clearer, but needs Rust 1.82.
+        #[allow(clippy::unnecessary_map_or)]
+        let long_enough = |period: Option<f64>| period.map_or(true, |p| p >= min_period);
+        loop {
+            let meander: Self = rng.gen();
+            if long_enough(meander.period()) && meander.curves.iter().all(|c| long_enough(c.period())) {
+                return meander;
+            }
+        }
+    }
+    /// Create a meander from the frequencies and phases of its components, which
+    /// must each have `N * D` elements.
+    ///
+    /// The parameters of component `k` of the curve for variable `i` are at index
+    /// `i * N + k`. So with the default 3 components, the first three elements of
+    /// each slice are for variable 0, the next three are for variable 1, and so on.
+    /// The components have the default amplitude, offset and weight.
+    ///
+    /// This returns an error if either slice has the wrong length, or if any of the
+    /// frequencies isn't positive.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::{Meander, ParamError};
+    ///
+    /// let frequencies = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let phases = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5];
+    /// let m: Meander<U2> = Meander::from_params(&frequencies, &phases).unwrap();
+    /// assert_eq!(m.curves[1].components[0].frequency, 4.0);
+    /// assert_eq!(m.curves[1].components[2].phase, 0.5);
+    ///
+    /// let err = Meander::<U2>::from_params(&frequencies[..5], &phases);
+    /// assert_eq!(err, Err(ParamError::WrongLength { expected: 6, found: 5 }));
+    /// let err = Meander::<U2>::from_params(&frequencies, &phases[..4]);
+    /// assert_eq!(err, Err(ParamError::WrongLength { expected: 6, found: 4 }));
+    /// let err = Meander::<U2>::from_params(&[1.0, 2.0, 0.0, 4.0, -5.0, 6.0], &phases);
+    /// assert_eq!(err, Err(ParamError::NonPositiveFrequency { index: 2, frequency: 0.0 }));
+    /// ```
+    pub fn from_params(frequencies: &[f64], phases: &[f64]) -> Result<Self, ParamError> {
+        let expected = D::to_usize() * N::to_usize();
+        for len in [frequencies.len(), phases.len()] {
+            if len != expected {
+                return Err(ParamError::WrongLength { expected, found: len });
+            }
+        }
+        if let Some((index, &frequency)) = frequencies.iter()
+            .enumerate()
+            .find(|&(_, &f)| f.is_nan() || f <= 0.0)
+        {
+            return Err(ParamError::NonPositiveFrequency { index, frequency });
+        }
+        let mut params = frequencies.iter().zip(phases.iter());
+        let curves = GenericArray::generate(|_| Meander1D {
+            components: GenericArray::generate(|_| {
+                let (&frequency, &phase) = params.next().unwrap();
+                UnitSinusoid { frequency, phase, ..Default::default() }
+            }),
+            envelope: default_envelope(),
+        });
+        Ok(Meander { curves })
+    }
+    /// Replace the curve of variable `dim` with `Meander1D::constant(value)`, so
+    /// that it stays fixed at `value` while the other variables keep moving.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let mut rng = meander::rand::thread_rng();
+    /// let mut m: Meander<U3> = meander::rand::random();
+    /// m.pin(0, 0.7);
+    /// let values: Vec<_> = m.time_steps(0.05).take(100).collect();
+    /// assert!(values.iter().all(|v| v[0] == 0.7));
+    /// assert!(values.iter().any(|v| v[1] != values[0][1]));
+    ///
+    /// m.unpin(0, &mut rng);
+    /// assert!(m.time_steps(0.05).take(100).any(|v| v[0] != 0.7));
+    /// ```
+    pub fn pin(&mut self, dim: usize, value: f64) {
+        self.curves[dim] = Meander1D::constant(value);
+    }
+    /// Replace the curve of variable `dim` with a new random curve, as sampled by
+    /// `rand::random()`. This undoes `pin`.
+    pub fn unpin<R: Rng + ?Sized>(&mut self, dim: usize, rng: &mut R) {
+        self.curves[dim] = rng.gen();
+    }
+    /// Generate `n` random meanders, as if by calling `rng.gen()` `n` times.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// ```
+    /// use meander::rand::{Rng, SeedableRng};
+    /// use meander::rand::rngs::StdRng;
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let batch: Vec<Meander<U3>> = Meander::sample_n(&mut StdRng::seed_from_u64(5), 50);
+    /// let mut rng = StdRng::seed_from_u64(5);
+    /// let single: Vec<Meander<U3>> = (0..50).map(|_| rng.gen()).collect();
+    /// assert_eq!(batch, single);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sample_n<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<Self> {
+        let mut out = Vec::with_capacity(n);
+        out.extend((0..n).map(|_| rng.sample(Standard)));
+        out
+    }
+    /// Generate a random meander whose component frequencies are spread out evenly.
+    /// This is the same as sampling from `QuasiDist::default()`.
+    pub fn random_quasi<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.sample(QuasiDist::default())
+    }
+}
+
+/// A meander through 2-dimensional space.
+pub type Meander2 = Meander<U2>;
+
+/// A meander through 3-dimensional space.
+pub type Meander3 = Meander<U3>;
+
+impl<D, W> Meander<D, U3, W>
+where
+    D: ArrayLength<Meander1D<U3, W>> + ArrayLength<[UnitSinusoid<W>; 3]>,
+    W: Copy,
+{
+    /// Return copies of the three component sinusoids of the curve of each
+    /// variable, as given by `Meander1D::components`.
+    ///
+    /// As there, each curve's `envelope` is separate, multiplies its output, and is
+    /// left out here.
+    ///
+    /// ```
+    /// use meander::typenum::U2;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U2> = meander::rand::random();
+    /// let spectrum = m.spectrum();
+    /// for (sinusoids, &value) in spectrum.iter().zip(m.evaluate(0.8).iter()) {
+    ///     let sum: f64 = sinusoids.iter().map(|s| s.evaluate(0.8)).sum();
+    ///     assert!((sum / 3.0 - value).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn spectrum(&self) -> GenericArray<[UnitSinusoid<W>; 3], D> {
+        self.curves.iter().map(|c| c.components()).collect()
+    }
+}
+
+impl<N, W> Meander<U2, N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Find the values of both variables at a particular point in time, as a tuple.
+    ///
+    /// ```
+    /// use meander::Meander2;
+    ///
+    /// let m: Meander2 = meander::rand::random();
+    /// let (x, y) = m.xy(0.5);
+    /// assert_eq!(x, m.evaluate(0.5)[0]);
+    /// assert_eq!(y, m.evaluate(0.5)[1]);
+    /// ```
+    pub fn xy(&self, t: f64) -> (f64, f64) {
+        (self.curves[0].evaluate(t), self.curves[1].evaluate(t))
+    }
+}
+
+impl<N, W> Meander<U3, N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Find the values of all three variables at a particular point in time, as a
+    /// tuple.
+    ///
+    /// ```
+    /// use meander::Meander3;
+    ///
+    /// let m: Meander3 = meander::rand::random();
+    /// let (r, g, b) = m.xyz(0.5);
+    /// assert_eq!((r, g, b), (m.evaluate(0.5)[0], m.evaluate(0.5)[1], m.evaluate(0.5)[2]));
+    /// ```
+    pub fn xyz(&self, t: f64) -> (f64, f64, f64) {
+        (
+            self.curves[0].evaluate(t),
+            self.curves[1].evaluate(t),
+            self.curves[2].evaluate(t),
+        )
+    }
+    /// Find the values of all three variables at a particular point in time, as an
+    /// array.
+    ///
+    /// This gives the same values as `evaluate`, but writes them directly into a
+    /// plain array, which is a little faster for the common case of meandering
+    /// through colors.
+    ///
+    /// ```
+    /// use meander::Meander3;
+    ///
+    /// let m: Meander3 = meander::rand::random();
+    /// for i in 0..1000 {
+    ///     let t = i as f64 * 0.01;
+    ///     assert_eq!(&m.evaluate_array(t)[..], &m.evaluate(t)[..]);
+    /// }
+    /// ```
+    pub fn evaluate_array(&self, t: f64) -> [f64; 3] {
+        [
+            self.curves[0].evaluate(t),
+            self.curves[1].evaluate(t),
+            self.curves[2].evaluate(t),
+        ]
+    }
+}
+
+#[cfg(feature = "palette")]
+impl<N, W> Meander<U3, N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Find the color whose red, green and blue channels are given by the three
+    /// variables at a particular point in time.
+    ///
+    /// This requires the `palette` feature.
+    ///
+    /// ```
+    /// use meander::Meander3;
+    ///
+    /// let m: Meander3 = meander::rand::random();
+    /// let color = m.to_srgb(0.5);
+    /// assert_eq!((color.red, color.green, color.blue), m.xyz(0.5));
+    /// ```
+    pub fn to_srgb(&self, t: f64) -> palette::Srgb<f64> {
+        let (r, g, b) = self.xyz(t);
+        palette::Srgb::new(r, g, b)
+    }
+    /// Find the color whose hue, saturation and value are given by the three
+    /// variables at a particular point in time.
+    ///
+    /// The hue is scaled from `[0, 1]` to `[0°, 360°]`, so a hue of 0 and a hue of 1
+    /// are the same color. Meandering through HSV tends to give more pleasing
+    /// sequences of colors than meandering through RGB.
+    ///
+    /// This requires the `palette` feature.
+    ///
+    /// ```
+    /// use meander::generic_array::arr;
+    /// use meander::palette::RgbHue;
+    /// use meander::{Meander3, Meander1D, UnitSinusoid};
+    ///
+    /// let m: Meander3 = meander::rand::random();
+    /// let color = m.to_hsv(0.5);
+    /// let (h, s, v) = m.xyz(0.5);
+    /// assert_eq!(color.hue, RgbHue::from_degrees(h * 360.0));
+    /// assert_eq!((color.saturation, color.value), (s, v));
+    ///
+    /// // The hue is 0 at `t = 0` and 1 at `t = 0.5`, which are both red.
+    /// let hue = UnitSinusoid::new(1.0, 0.0);
+    /// let full = UnitSinusoid { offset: 1.0, amplitude: 0.0, ..hue };
+    /// let m: Meander3 = Meander3::from_curves(arr![Meander1D;
+    ///     Meander1D::new(hue, hue, hue),
+    ///     Meander1D::new(full, full, full),
+    ///     Meander1D::new(full, full, full),
+    /// ]);
+    /// assert_eq!(m.to_hsv(0.0).hue, m.to_hsv(0.5).hue);
+    /// assert_eq!(m.to_hsv(0.5).hue.into_positive_degrees(), 0.0);
+    /// ```
+    pub fn to_hsv(&self, t: f64) -> palette::Hsv<palette::encoding::Srgb, f64> {
+        let (h, s, v) = self.xyz(t);
+        palette::Hsv::new(h * 360.0, s, v)
+    }
+}
+
+/// An iterator over the values of a meander's variables at a fixed number of
+/// evenly spaced points in time.
+///
+/// This is created by `Meander::time_steps_bounded`.
+#[derive(Clone, Debug)]
+pub struct BoundedTimeSteps<'a, D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    meander: &'a Meander<D, N, W>,
+    dt: f64,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, D, N, W> Iterator for BoundedTimeSteps<'a, D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    type Item = GenericArray<f64, D>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let t = self.front as f64 * self.dt;
+        self.front += 1;
+        Some(self.meander.evaluate(t))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, D, N, W> DoubleEndedIterator for BoundedTimeSteps<'a, D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.meander.evaluate(self.back as f64 * self.dt))
+    }
+}
+
+impl<'a, D, N, W> ExactSizeIterator for BoundedTimeSteps<'a, D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{}
+
+impl<'a, D, N, W> core::iter::FusedIterator for BoundedTimeSteps<'a, D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{}
+
+/// The iterator returned by `Meander::time_steps`.
+struct TimeSteps<'a, D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    meander: &'a Meander<D, N, W>,
+    dt: f64,
+    step: u64,
+}
+
+impl<'a, D, N, W> Iterator for TimeSteps<'a, D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    type Item = GenericArray<f64, D>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let t = self.step as f64 * self.dt;
+        self.step += 1;
+        Some(self.meander.evaluate(t))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.step += n as u64;
+        self.next()
+    }
+}
+
+/// An iterator over the values of a meander's variables at intervals of `dt`,
+/// meant for streaming them in real time.
+///
+/// This is created by `Meander::stepper`. Rather than finding how far through its
+/// cycle each component is from scratch at every step, this keeps track of it and
+/// advances it by a fixed amount each step. (Envelopes that aren't constant are
+/// still evaluated from scratch.) So the `n`th item is the same as
+/// `evaluate(n as f64 * dt)`, except for rounding error that slowly builds up over
+/// many steps.
+///
+/// ```
+/// use meander::typenum::U3;
+/// use meander::Meander;
+///
+/// let m: Meander<U3> = meander::rand::random();
+/// let expected = m.clone().into_time_steps(0.01);
+/// for (a, b) in m.stepper(0.01).zip(expected).take(1000) {
+///     for i in 0..3 {
+///         assert!((a[i] - b[i]).abs() < 1e-9);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MeanderStepper<D, N = U3, W = Haversin>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<GenericArray<f64, N>>,
+    N: ArrayLength<UnitSinusoid<W>> + ArrayLength<f64>,
+{
+    meander: Meander<D, N, W>,
+    fractions: GenericArray<GenericArray<f64, N>, D>,
+    deltas: GenericArray<GenericArray<f64, N>, D>,
+    dt: f64,
+    step: u64,
+}
+
+impl<D, N, W> Iterator for MeanderStepper<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<GenericArray<f64, N>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>> + ArrayLength<f64>,
+    W: Waveform,
+{
+    type Item = GenericArray<f64, D>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut out = GenericArray::default();
+        let t = self.step as f64 * self.dt;
+        self.step += 1;
+        let states = self.fractions.iter_mut().zip(self.deltas.iter());
+        for ((o, c), (fractions, deltas)) in out.iter_mut().zip(self.meander.curves.iter()).zip(states) {
+            *o = c.weighted_mean(c.components.iter()
+                .zip(fractions.iter())
+                .map(|(s, &x)| s.offset + s.amplitude * s.waveform.value(x)));
+            *o *= c.envelope_value(t);
+            for (x, &dx) in fractions.iter_mut().zip(deltas.iter()) {
+                *x += dx;
+                if *x >= 1.0 {
+                    *x -= 1.0;
+                }
+            }
+        }
+        Some(out)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// An iterator over the values of a meander's variables at intervals of `dt`,
+/// which owns the meander.
+///
+/// This is created by `Meander::iter`. It can be cloned to restart iteration from
+/// the current step.
+///
+/// The time of each step is computed as `step as f64 * dt`, where `step` is a
+/// `u64`, rather than by repeatedly adding `dt`. This means that the time of every
+/// step is within rounding error of its true value, instead of drifting further
+/// from it with each step.
+///
+/// ```
+/// use meander::typenum::U3;
+/// use meander::Meander;
+///
+/// let m: Meander<U3> = meander::rand::random();
+/// let dt = 0.01;
+/// let steps = 10_000_000;
+/// let mut iter = m.clone().iter(dt);
+/// assert_eq!(iter.nth(steps), Some(m.evaluate(steps as f64 * dt)));
+///
+/// // Adding `dt` repeatedly drifts away from the true time.
+/// let accumulated = (0..steps).fold(0.0, |t, _| t + dt);
+/// let multiplied = steps as f64 * dt;
+/// assert!((multiplied - 100_000.0).abs() < 1e-9);
+/// assert!((accumulated - 100_000.0).abs() > 1e-6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MeanderIter<D, N = U3, W = Haversin>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    meander: Meander<D, N, W>,
+    dt: f64,
+    step: u64,
+}
+
+/// Another name for `MeanderIter`, for code that uses `position` and `seek` to
+/// save and restore its place.
+///
+/// ```
+/// use meander::typenum::U3;
+/// use meander::{Meander, MeanderCursor};
+///
+/// let m: Meander<U3> = meander::rand::random();
+/// let mut cursor: MeanderCursor<U3> = m.clone().iter(0.01);
+/// cursor.nth(41);
+/// let saved = cursor.position();
+/// assert_eq!(saved, 42);
+///
+/// let mut resumed = m.clone().iter(0.01);
+/// resumed.seek(saved);
+/// assert_eq!(resumed.next(), cursor.next());
+///
+/// resumed.seek(500);
+/// assert_eq!(resumed.next(), Some(m.evaluate(500.0 * 0.01)));
+/// assert_eq!(resumed.position(), 501);
+/// ```
+pub type MeanderCursor<D, N = U3, W = Haversin> = MeanderIter<D, N, W>;
+
+impl<D, N, W> MeanderIter<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// Return the index of the step that will be yielded next. The time of that
+    /// step is `position() as f64 * dt`.
+    pub fn position(&self) -> u64 {
+        self.step
+    }
+    /// Move to step `step`, so that it is the one yielded next. This can move both
+    /// forward and backward, and takes constant time.
+    pub fn seek(&mut self, step: u64) {
+        self.step = step;
+    }
+}
+
+impl<D, N, W> Iterator for MeanderIter<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    type Item = GenericArray<f64, D>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let t = self.step as f64 * self.dt;
+        self.step += 1;
+        Some(self.meander.evaluate(t))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.step += n as u64;
+        self.next()
+    }
+}
+
+impl<D, N, W> core::iter::FusedIterator for MeanderIter<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{}
+
+/// A meander whose variables are all passed through a function `F` when they are
+/// evaluated. This is created by `Meander::map_output`.
+#[derive(Clone)]
+pub struct MappedMeander<D, F, N = U3, W = Haversin>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    meander: Meander<D, N, W>,
+    f: F,
+}
+
+impl<D, F, N, W> MappedMeander<D, F, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    F: Fn(f64) -> f64 + Clone,
+    W: Waveform,
+{
+    /// Find the value of each of the variables at a particular point in time, and
+    /// apply `F` to each of them.
+    pub fn evaluate(&self, t: f64) -> GenericArray<f64, D> {
+        self.meander.evaluate(t).map(&self.f)
+    }
+    /// Return an iterator yielding the mapped values of the variables at intervals
+    /// of `dt`.
+    pub fn time_steps<'a>(&'a self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> + 'a {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+    /// Return an iterator yielding the mapped values of the variables at intervals
+    /// of `dt`. Consumes `self`.
+    pub fn into_time_steps(self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> {
+        let f = self.f;
+        self.meander.into_time_steps(dt).map(move |v| v.map(&f))
+    }
+    /// Return the meander that this wraps, discarding `F`.
+    pub fn into_inner(self) -> Meander<D, N, W> {
+        self.meander
+    }
+}
+
+/// A meander whose variables are passed through a contrast curve when they are
+/// evaluated, so that they reach the extremes of `[0, 1]` more often. This is
+/// sampled from `HighContrastDist`, which describes the curve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HighContrastMeander<D, N = U3, W = Haversin>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    meander: Meander<D, N, W>,
+    contrast: f64,
+}
+
+impl<D, N, W> HighContrastMeander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Find the value of each of the variables at a particular point in time, with
+    /// the contrast applied.
+    pub fn evaluate(&self, t: f64) -> GenericArray<f64, D> {
+        let contrast = self.contrast;
+        self.meander.evaluate(t).map(|v| apply_contrast(v, contrast))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of
+    /// `dt`, with the contrast applied.
+    pub fn time_steps<'a>(&'a self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> + 'a {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of
+    /// `dt`, with the contrast applied. Consumes `self`.
+    pub fn into_time_steps(self, dt: f64) -> impl Iterator<Item=GenericArray<f64, D>> {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+    /// Return the meander that this wraps, without the contrast applied.
+    pub fn inner(&self) -> &Meander<D, N, W> {
+        &self.meander
+    }
+    /// Return the meander that this wraps, discarding the contrast.
+    pub fn into_inner(self) -> Meander<D, N, W> {
+        self.meander
+    }
+    /// Return the contrast applied to the values of the variables.
+    pub fn contrast(&self) -> f64 {
+        self.contrast
+    }
+}
+
+/// Pass `v` through the contrast curve described by `HighContrastDist`.
+fn apply_contrast(v: f64, contrast: f64) -> f64 {
+    let v = v.clamp(0.0, 1.0);
+    // Equal to `v^c / (v^c + (1 - v)^c)`, but without dividing 0 by 0 when both
+    // powers underflow.
+    1.0 / (1.0 + math::pow((1.0 - v) / v, contrast))
+}
+
+#[cfg(feature = "std")]
+impl<D, N, W> Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Write the values of the variables at the first `n` intervals of `dt` to
+    /// `writer` as CSV.
+    ///
+    /// The first row is a header `t,v0,v1,...` with one `v` column per variable,
+    /// and each following row holds a point in time and the values at that time.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let mut buf = Vec::new();
+    /// m.to_csv(&mut buf, 0.1, 20).unwrap();
+    ///
+    /// let csv = String::from_utf8(buf).unwrap();
+    /// let mut lines = csv.lines();
+    /// assert_eq!(lines.next(), Some("t,v0,v1,v2"));
+    /// let first: Vec<f64> = lines.next().unwrap().split(',').map(|v| v.parse().unwrap()).collect();
+    /// assert_eq!(first[0], 0.0);
+    /// assert_eq!(&first[1..], m.evaluate(0.0).as_slice());
+    /// assert_eq!(lines.count(), 19);
+    /// ```
+    pub fn to_csv<O: std::io::Write>(&self, mut writer: O, dt: f64, n: usize) -> std::io::Result<()> {
+        write!(writer, "t")?;
+        for i in 0..self.curves.len() {
+            write!(writer, ",v{}", i)?;
+        }
+        writeln!(writer)?;
+        for i in 0..n {
+            let t = i as f64 * dt;
+            write!(writer, "{}", t)?;
+            for v in self.evaluate(t) {
+                write!(writer, ",{}", v)?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<D, N, W> Meander<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform + Sync,
+{
+    /// Return a parallel iterator yielding the values of the variables at the first
+    /// `n` intervals of `dt`. These are the same values as `time_steps(dt).take(n)`.
+    ///
+    /// This requires the `rayon` feature.
+    pub fn par_time_steps<'a>(&'a self, dt: f64, n: usize)
+        -> impl IndexedParallelIterator<Item=GenericArray<f64, D>> + 'a
+    {
+        (0..n).into_par_iter().map(move |i| self.evaluate(i as f64 * dt))
+    }
+    /// Evaluate the variables at `n` points in time, starting at `t0` and spaced
+    /// `dt` apart, in parallel.
+    ///
+    /// This requires the `rayon` feature.
+    ///
+    /// ```
+    /// use meander::typenum::U3;
+    /// use meander::Meander;
+    ///
+    /// let m: Meander<U3> = meander::rand::random();
+    /// let parallel = m.par_evaluate_range(0.0, 0.01, 10_000);
+    /// let sequential: Vec<_> = m.time_steps(0.01).take(10_000).collect();
+    /// assert_eq!(parallel, sequential);
+    /// ```
+    pub fn par_evaluate_range(&self, t0: f64, dt: f64, n: usize) -> Vec<GenericArray<f64, D>> {
+        (0..n).into_par_iter().map(|i| self.evaluate(t0 + i as f64 * dt)).collect()
+    }
+}
+
+/// Represents a curve that meanders through `D`-dimensional space, using a const
+/// generic parameter rather than a `typenum` type.
+///
+/// This is equivalent to `Meander`, but stores its curves in an ordinary array.
+/// It can be generated randomly using `rand::random()`.
+///
+/// ```
+/// use meander::typenum::U3;
+/// use meander::{ConstMeander, Meander};
+///
+/// let m: Meander<U3> = meander::rand::random();
+/// let c = ConstMeander::<3> {
+///     curves: [m.curves[0].clone(), m.curves[1].clone(), m.curves[2].clone()],
+/// };
+/// for &t in &[0.0, 0.5, 12.25] {
+///     assert_eq!(&c.evaluate(t)[..], m.evaluate(t).as_slice());
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConstMeander<const D: usize, N = U3, W = Haversin>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// Each variable is controlled by a separate 1-dimensional function defined here.
+    pub curves: [Meander1D<N, W>; D],
+}
+
+impl<const D: usize, N, W> Distribution<ConstMeander<D, N, W>> for Standard
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Default,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ConstMeander<D, N, W> {
+        ConstMeander {
+            curves: core::array::from_fn(|_| rng.gen()),
+        }
+    }
+}
+
+impl<const D: usize, N, W> ConstMeander<D, N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Find the value of each of the variables at a particular point in time.
+    pub fn evaluate(&self, t: f64) -> [f64; D] {
+        core::array::from_fn(|i| self.curves[i].evaluate(t))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    pub fn time_steps<'a>(&'a self, dt: f64) -> impl Iterator<Item=[f64; D]> + 'a {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    /// Consumes `self`.
+    pub fn into_time_steps(self, dt: f64) -> impl Iterator<Item=[f64; D]> {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+}
+
+/// Represents a curve that meanders through a space whose number of dimensions is
+/// only known at runtime.
+///
+/// This is the same as `Meander`, except that the curves are stored in a `Vec`
+/// rather than a `GenericArray`.
+///
+/// ```
+/// use meander::typenum::U3;
+/// use meander::{DynMeander, Meander};
+///
+/// let m: Meander<U3> = meander::rand::random();
+/// let d = DynMeander { curves: m.curves.to_vec() };
+/// for &t in &[0.0, 0.5, 12.25] {
+///     assert_eq!(d.evaluate(t).as_slice(), m.evaluate(t).as_slice());
+/// }
+/// ```
+///
+/// This requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(bound(serialize = "", deserialize = "W: Default")),
+)]
+pub struct DynMeander<N = U3, W = Haversin>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// Each variable is controlled by a separate 1-dimensional function defined here.
+    pub curves: Vec<Meander1D<N, W>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<N, W> DynMeander<N, W>
+where
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform + Default,
+{
+    /// Generate a random meander with `n` variables.
+    pub fn random<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+        DynMeander {
+            curves: (0..n).map(|_| rng.gen()).collect(),
+        }
+    }
+    /// Find the value of each of the variables at a particular point in time.
+    pub fn evaluate(&self, t: f64) -> Vec<f64> {
+        self.curves.iter().map(|c| c.evaluate(t)).collect()
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    pub fn time_steps<'a>(&'a self, dt: f64) -> impl Iterator<Item=Vec<f64>> + 'a {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    /// Consumes `self`.
+    pub fn into_time_steps(self, dt: f64) -> impl Iterator<Item=Vec<f64>> {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+}
+
+/// A group of meanders that are all evaluated at the same point in time, which is
+/// kept by the group.
+///
+/// This keeps several animations in lockstep without having to manage a separate
+/// iterator for each of them. `step_all` moves the shared time forward, and
+/// `set_time` can be used to jump it to an external clock.
+///
+/// This requires the `alloc` feature.
+///
+/// ```
+/// use meander::typenum::U3;
+/// use meander::{Meander, MeanderGroup};
+///
+/// let a: Meander<U3> = meander::rand::random();
+/// let b: Meander<U3> = meander::rand::random();
+/// let mut group = MeanderGroup::new(vec![a.clone(), b.clone()]);
+/// assert_eq!(group.time(), 0.0);
+/// for _ in 0..4 {
+///     group.step_all(0.25);
+/// }
+/// assert_eq!(group.time(), 1.0);
+/// assert_eq!(group.values(), vec![a.evaluate(1.0), b.evaluate(1.0)]);
+///
+/// group.set_time(10.0);
+/// group.step_all(0.5);
+/// assert_eq!(group.values(), group.evaluate_all(10.5));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct MeanderGroup<D, N = U3, W = Haversin>
+where
+    D: ArrayLength<Meander1D<N, W>>,
+    N: ArrayLength<UnitSinusoid<W>>,
+{
+    /// The meanders in the group.
+    pub meanders: Vec<Meander<D, N, W>>,
+    time: f64,
+}
+
+#[cfg(feature = "alloc")]
+impl<D, N, W> MeanderGroup<D, N, W>
+where
+    D: ArrayLength<Meander1D<N, W>> + ArrayLength<f64>,
+    N: ArrayLength<UnitSinusoid<W>>,
+    W: Waveform,
+{
+    /// Create a group of meanders whose shared time starts at 0.
+    pub fn new(meanders: Vec<Meander<D, N, W>>) -> Self {
+        MeanderGroup { meanders, time: 0.0 }
+    }
+    /// Return the shared time.
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+    /// Set the shared time to `t`.
+    pub fn set_time(&mut self, t: f64) {
+        self.time = t;
+    }
+    /// Move the shared time forward by `dt`.
+    ///
+    /// Since this adds `dt` to the time, rounding error accumulates over many steps
+    /// (see `Meander::time_steps_accumulated`). Use `set_time` to resynchronize with
+    /// an exact clock.
+    pub fn step_all(&mut self, dt: f64) {
+        self.time += dt;
+    }
+    /// Find the values of the variables of every meander at the shared time.
+    pub fn values(&self) -> Vec<GenericArray<f64, D>> {
+        self.evaluate_all(self.time)
+    }
+    /// Find the values of the variables of every meander at time `t`.
+    pub fn evaluate_all(&self, t: f64) -> Vec<GenericArray<f64, D>> {
+        self.meanders.iter().map(|m| m.evaluate(t)).collect()
+    }
+}
+
+/// A table of precomputed values of a curve over one period, which can be sampled
+/// with linear interpolation. Created by `Meander1D::build_lut`.
+///
+/// Sampling a `Lut` costs a lookup and an interpolation instead of evaluating
+/// every component of the curve, in exchange for storing the table. The
+/// interpolation error shrinks with the square of the resolution: doubling the
+/// resolution makes the table twice as large and the error about four times
+/// smaller. Curves with higher frequency components need a higher resolution for
+/// the same accuracy.
+///
+/// This requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lut {
+    period: f64,
+    values: Vec<f64>,
+}
+
+#[cfg(feature = "alloc")]
+impl Lut {
+    /// Find the value of the curve at time `t`, interpolating linearly between the
+    /// two nearest entries of the table. `t` is taken modulo the period, so it may
+    /// be negative or greater than the period.
+    pub fn sample(&self, t: f64) -> f64 {
+        let cycles = t / self.period;
+        let x = (cycles - math::floor(cycles)) * self.values.len() as f64;
+        let i = (x as usize).min(self.values.len() - 1);
+        let a = self.values[i];
+        let b = self.values[(i + 1) % self.values.len()];
+        a + (b - a) * (x - i as f64)
+    }
+    /// Return the period of the table.
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+}
+
+/// Represents a curve that meanders through space, where each variable can be
+/// controlled by a different type of curve.
+///
+/// This is like `DynMeander`, except that each curve is a `Box<dyn Curve>`, so
+/// custom implementations of `Curve` can be mixed in with `Meander1D`s.
+///
+/// ```
+/// use meander::{BoxedMeander, Curve, Meander1D, UnitSinusoid};
+///
+/// let c: Meander1D = meander::rand::random();
+/// let m = BoxedMeander {
+///     curves: vec![Box::new(c.clone()), Box::new(UnitSinusoid::new(2.0, 0.0))],
+/// };
+/// let values: Vec<_> = m.time_steps(0.25).take(3).collect();
+/// assert_eq!(values[1], vec![c.evaluate(0.25), 1.0]);
+/// ```
+///
+/// This requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub struct BoxedMeander {
+    /// Each variable is controlled by a separate 1-dimensional function defined here.
+    pub curves: Vec<Box<dyn Curve>>,
+}
+
+#[cfg(feature = "alloc")]
+impl BoxedMeander {
+    /// Find the value of each of the variables at a particular point in time.
+    pub fn evaluate(&self, t: f64) -> Vec<f64> {
+        self.curves.iter().map(|c| c.evaluate(t)).collect()
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    pub fn time_steps<'a>(&'a self, dt: f64) -> impl Iterator<Item=Vec<f64>> + 'a {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
+    }
+    /// Return an iterator yielding the values of the variables at intervals of `dt`.
+    /// Consumes `self`.
+    pub fn into_time_steps(self, dt: f64) -> impl Iterator<Item=Vec<f64>> {
+        (0u64..).map(move |i| self.evaluate(i as f64 * dt))
     }
 }
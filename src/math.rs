@@ -0,0 +1,23 @@
+//! Floating-point functions that aren't available in `core`.
+//!
+//! With the `std` feature these forward to the inherent methods on `f64` and `f32`;
+//! otherwise they are provided by `libm`.
+
+#[cfg(feature = "std")]
+mod imp {
+    pub fn cos(x: f64) -> f64 { x.cos() }
+    pub fn sin(x: f64) -> f64 { x.sin() }
+    pub fn floor(x: f64) -> f64 { x.floor() }
+    pub fn round(x: f64) -> f64 { x.round() }
+    pub fn sqrt(x: f64) -> f64 { x.sqrt() }
+    pub fn acos(x: f64) -> f64 { x.acos() }
+    pub fn pow(x: f64, y: f64) -> f64 { x.powf(y) }
+    pub fn cosf(x: f32) -> f32 { x.cos() }
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+mod imp {
+    pub use libm::{cos, sin, floor, round, sqrt, acos, pow, cosf};
+}
+
+pub use self::imp::*;
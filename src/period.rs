@@ -0,0 +1,78 @@
+//! Finding the period of a sum of sinusoids.
+
+use crate::math;
+
+/// The largest denominator considered when checking whether the ratio of two
+/// frequencies is rational.
+pub const MAX_DENOMINATOR: u64 = 10_000;
+
+/// The relative error allowed when checking whether the ratio of two frequencies
+/// is rational.
+pub const TOLERANCE: f64 = 1e-12;
+
+/// Find the fraction with the smallest denominator lying within `TOLERANCE` of `x`,
+/// using its continued fraction expansion. `x` must be positive.
+fn rational_approximation(x: f64) -> Option<(u64, u64)> {
+    let (mut h0, mut h1) = (0u64, 1u64);
+    let (mut k0, mut k1) = (1u64, 0u64);
+    let mut r = x;
+    loop {
+        let a = math::floor(r);
+        if a >= u64::MAX as f64 {
+            return None;
+        }
+        let a = a as u64;
+        let h2 = a.checked_mul(h1)?.checked_add(h0)?;
+        let k2 = a.checked_mul(k1)?.checked_add(k0)?;
+        if k2 > MAX_DENOMINATOR {
+            return None;
+        }
+        if (h2 as f64 / k2 as f64 - x).abs() <= TOLERANCE * x {
+            return Some((h2, k2));
+        }
+        let frac = r - a as f64;
+        if frac <= 0.0 {
+            return None;
+        }
+        r = frac.recip();
+        h0 = h1;
+        h1 = h2;
+        k0 = k1;
+        k1 = k2;
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+fn lcm(a: u64, b: u64) -> Option<u64> {
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Find the smallest positive `p` such that `f * p` is an integer for each of the
+/// given frequencies `f`, or `None` if there is no such `p` (within tolerance), if
+/// there are no frequencies, or if any of them are not positive.
+pub fn common_period<I: IntoIterator<Item=f64>>(frequencies: I) -> Option<f64> {
+    let mut frequencies = frequencies.into_iter();
+    let base = frequencies.next()?;
+    if !(base > 0.0 && base.is_finite()) {
+        return None;
+    }
+    // Every frequency seen so far is an integer multiple of `base * a / b`.
+    let (mut a, mut b) = (1, 1);
+    for f in frequencies {
+        if !(f > 0.0 && f.is_finite()) {
+            return None;
+        }
+        let (p, q) = rational_approximation(f / base)?;
+        a = gcd(a, p);
+        b = lcm(b, q)?;
+    }
+    Some(b as f64 / (base * a as f64))
+}
@@ -0,0 +1,60 @@
+use crate::math;
+
+/// The shape of a single cycle of a `UnitSinusoid`.
+///
+/// `value` is given how far through the cycle the sinusoid is, as a number in
+/// `[0, 1)`, and should return a value in `[0, 1]`. To match the behavior of
+/// `Haversin`, it should be 0 at the start of the cycle and 1 halfway through it.
+pub trait Waveform: Copy {
+    /// Find the value of the waveform at a given fraction of the way through a cycle.
+    fn value(&self, phase_fraction: f64) -> f64;
+}
+
+/// The waveform `(1 - cos(2πx)) / 2`. This is the default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Haversin;
+
+impl Waveform for Haversin {
+    fn value(&self, phase_fraction: f64) -> f64 {
+        (1.0 - math::cos(crate::PI2 * phase_fraction)) / 2.0
+    }
+}
+
+/// A waveform that rises linearly from 0 to 1 over the first half of the cycle and
+/// falls linearly back to 0 over the second half.
+///
+/// ```
+/// use meander::{Triangle, Waveform};
+///
+/// assert_eq!(Triangle.value(0.0), 0.0);
+/// assert_eq!(Triangle.value(0.25), 0.5);
+/// assert_eq!(Triangle.value(0.5), 1.0);
+/// assert_eq!(Triangle.value(0.75), 0.5);
+/// assert_eq!(Triangle.value(0.125) + Triangle.value(0.375), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Triangle;
+
+impl Waveform for Triangle {
+    fn value(&self, phase_fraction: f64) -> f64 {
+        if phase_fraction < 0.5 {
+            2.0 * phase_fraction
+        } else {
+            2.0 - 2.0 * phase_fraction
+        }
+    }
+}
+
+/// A `Triangle` wave passed through the smoothstep function `3x² - 2x³`.
+///
+/// This has the same peaks as a `Haversin`, but flattens out more near them, so
+/// it spends more of its time close to 0 and 1.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Smoothstep;
+
+impl Waveform for Smoothstep {
+    fn value(&self, phase_fraction: f64) -> f64 {
+        let x = Triangle.value(phase_fraction);
+        x * x * (3.0 - 2.0 * x)
+    }
+}